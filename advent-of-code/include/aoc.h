@@ -0,0 +1,85 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+/*
+ * C interface to the Advent of Code solutions.
+ *
+ * Build the shared library with `cargo rustc --lib --crate-type cdylib --features ffi` and
+ * link against libaoc.
+ *
+ * Both functions write a NUL terminated string into a caller provided buffer.
+ * `out_len` points to the size of that buffer on entry and receives the length of the string,
+ * excluding the NUL, on return.
+ * When the buffer is too small nothing is written, AOC_BUFFER_TOO_SMALL is returned, and
+ * `out_len` receives the size needed.
+ */
+
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define AOC_OK 0
+
+#define AOC_UNKNOWN_PUZZLE -1
+
+#define AOC_INVALID_ARGUMENT -2
+
+#define AOC_SOLUTION_PANICKED -3
+
+#define AOC_BUFFER_TOO_SMALL -4
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Solves one part of a puzzle, writing the answer into `out_buf`.
+
+ Returns `AOC_OK` on success, or one of the negative `AOC_*` error codes.
+
+ # Safety
+ `input` must be valid for reads of `input_len` bytes, `out_buf` must be valid for writes of
+ `*out_len` bytes, and `out_len` must be a valid pointer.
+ */
+int32_t aoc_solve(uint16_t year,
+                  uint8_t day,
+                  uint8_t part,
+                  const char *input,
+                  size_t input_len,
+                  char *out_buf,
+                  size_t *out_len);
+
+/*
+ Writes the registered puzzles into `out_buf` as `YYYY-12-DD` dates, one per line.
+
+ Returns `AOC_OK` on success, or one of the negative `AOC_*` error codes.
+
+ # Safety
+ `out_buf` must be valid for writes of `*out_len` bytes and `out_len` must be a valid pointer.
+ */
+int32_t aoc_list_puzzles(char *out_buf, size_t *out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_H */
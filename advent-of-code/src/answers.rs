@@ -0,0 +1,110 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::registry::{Part, PuzzleId};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Answers that were accepted on the Advent of Code website, recorded by hand.
+///
+/// The answers file has one answer per line in the form `YYYY-12-DD PART ANSWER`.
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    answers: HashMap<(PuzzleId, Part), String>,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn parse(contents: &str) -> Answers {
+        let answers = contents
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .filter_map(|l| {
+                let mut fields = l.splitn(3, ' ');
+                let id = fields.next()?.parse().ok()?;
+                let part = fields.next()?.parse().ok()?;
+                let answer = fields.next()?.trim().to_string();
+                Some(((id, part), answer))
+            })
+            .collect();
+
+        Answers { answers }
+    }
+
+    /// Loads the first answers file found in `paths`, or no answers if there isn't one.
+    #[must_use]
+    pub fn load<P: AsRef<Path>>(paths: &[P]) -> Answers {
+        paths
+            .iter()
+            .find_map(|p| std::fs::read_to_string(p).ok())
+            .map(|contents| Answers::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn get(&self, id: PuzzleId, part: Part) -> Option<&str> {
+        self.answers.get(&(id, part)).map(String::as_str)
+    }
+
+    /// The number of parts of a puzzle with a recorded answer.
+    #[must_use]
+    pub fn count(&self, id: PuzzleId) -> usize {
+        Part::all()
+            .into_iter()
+            .filter(|&part| self.get(id, part).is_some())
+            .count()
+    }
+}
+
+/// Where the answers file is looked for, in order.
+#[must_use]
+pub fn default_paths() -> Vec<String> {
+    vec!["answers.txt".into(), "inputs/answers.txt".into()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "\
+# 2024
+2024-12-01 1 11
+2024-12-01 2 31
+
+2024-12-17 1 4,6,3,5,6,3,5,2,1,0
+not an answer
+",
+        );
+
+        let day01 = PuzzleId { year: 2024, day: 1 };
+        let day17 = PuzzleId {
+            year: 2024,
+            day: 17,
+        };
+        assert_eq!(Some("11"), answers.get(day01, Part::One));
+        assert_eq!(Some("31"), answers.get(day01, Part::Two));
+        assert_eq!(Some("4,6,3,5,6,3,5,2,1,0"), answers.get(day17, Part::One));
+        assert_eq!(2, answers.count(day01));
+        assert_eq!(1, answers.count(day17));
+    }
+}
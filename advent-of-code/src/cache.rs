@@ -0,0 +1,248 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::registry::{Part, PuzzleId};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// A previously computed answer and how long it took to compute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedAnswer {
+    pub input_hash: u64,
+    pub build_id: u64,
+    pub elapsed: Duration,
+    pub answer: String,
+}
+
+/// Answers from earlier runs for each puzzle part and input, so switching between an example and
+/// the real input hits the cache for both.
+///
+/// An answer is only reused when both the input and the binary that computed it are unchanged,
+/// so recording an answer drops the answers to the same part from other builds. The cache is
+/// stored as a tab separated file with one answer per line, the latest for each part last.
+#[derive(Debug, Clone, Default)]
+pub struct AnswerCache {
+    path: PathBuf,
+    answers: HashMap<(PuzzleId, Part, u64), CachedAnswer>,
+    /// The input hash of the answer recorded last for each puzzle part.
+    latest: HashMap<(PuzzleId, Part), u64>,
+}
+
+impl AnswerCache {
+    /// Loads the cache at `path`. A missing or unreadable file is an empty cache.
+    #[must_use]
+    pub fn load(path: &Path) -> AnswerCache {
+        let mut cache = AnswerCache {
+            path: path.to_path_buf(),
+            ..AnswerCache::default()
+        };
+
+        let contents = std::fs::read_to_string(path).unwrap_or_default();
+        for (id, part, cached) in contents.lines().filter_map(parse_line) {
+            cache.insert(id, part, cached);
+        }
+
+        cache
+    }
+
+    /// # Errors
+    /// Returns an error if the cache directory or file could not be written.
+    pub fn save(&self) -> std::io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut entries: Vec<_> = self.answers.iter().collect();
+        entries.sort_unstable_by_key(|&(&(id, part, input_hash), _)| {
+            let latest = self.latest.get(&(id, part)) == Some(&input_hash);
+            (id, part, latest, input_hash)
+        });
+
+        let mut contents = String::new();
+        for ((id, part, _), cached) in entries {
+            contents.push_str(&format!(
+                "{id}\t{part}\t{:016x}\t{:016x}\t{}\t{}\n",
+                cached.input_hash,
+                cached.build_id,
+                cached.elapsed.as_nanos(),
+                cached.answer
+            ));
+        }
+
+        std::fs::write(&self.path, contents)
+    }
+
+    /// The answer for a puzzle part if it was computed from the same input by the same build.
+    #[must_use]
+    pub fn get(&self, id: PuzzleId, part: Part, input_hash: u64, build_id: u64) -> Option<&str> {
+        self.answers
+            .get(&(id, part, input_hash))
+            .filter(|c| c.build_id == build_id)
+            .map(|c| c.answer.as_str())
+    }
+
+    /// The latest answer recorded for a puzzle part, regardless of input or build.
+    #[must_use]
+    pub fn latest(&self, id: PuzzleId, part: Part) -> Option<&CachedAnswer> {
+        let input_hash = self.latest.get(&(id, part))?;
+        self.answers.get(&(id, part, *input_hash))
+    }
+
+    /// Records an answer, replacing any earlier answer for the same puzzle part and input, and
+    /// dropping the answers to the part from other builds, which can never be reused.
+    /// Answers spanning several lines or containing tabs can't be stored and are skipped.
+    pub fn insert(&mut self, id: PuzzleId, part: Part, cached: CachedAnswer) {
+        if cached.answer.contains(['\n', '\t']) {
+            return;
+        }
+
+        self.answers
+            .retain(|&(i, p, _), c| (i, p) != (id, part) || c.build_id == cached.build_id);
+        self.latest.insert((id, part), cached.input_hash);
+        self.answers.insert((id, part, cached.input_hash), cached);
+    }
+}
+
+fn parse_line(line: &str) -> Option<(PuzzleId, Part, CachedAnswer)> {
+    let mut fields = line.splitn(6, '\t');
+    let id = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let input_hash = u64::from_str_radix(fields.next()?, 16).ok()?;
+    let build_id = u64::from_str_radix(fields.next()?, 16).ok()?;
+    let elapsed = Duration::from_nanos(fields.next()?.parse().ok()?);
+    let answer = fields.next()?.to_string();
+
+    let cached = CachedAnswer {
+        input_hash,
+        build_id,
+        elapsed,
+        answer,
+    };
+
+    Some((id, part, cached))
+}
+
+/// The cache file under `$XDG_CACHE_HOME`, falling back to `~/.cache`.
+#[must_use]
+pub fn default_path() -> Option<PathBuf> {
+    let dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+
+    Some(dir.join("advent-of-code").join("answers.tsv"))
+}
+
+/// 64-bit FNV-1a hash, which unlike `DefaultHasher` is stable between builds.
+#[must_use]
+pub fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |h, &b| {
+        (h ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Identifies the running binary by hashing its contents, so rebuilding invalidates the cache.
+#[must_use]
+pub fn build_id() -> Option<u64> {
+    let exe = std::env::current_exe().ok()?;
+    let contents = std::fs::read(exe).ok()?;
+
+    Some(hash(&contents))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_round_trip() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-test-{}", std::process::id()));
+        let path = dir.join("answers.tsv");
+        let id = PuzzleId { year: 2024, day: 1 };
+
+        let mut cache = AnswerCache::load(&path);
+        assert_eq!(None, cache.get(id, Part::One, 1, 2));
+
+        let cached = CachedAnswer {
+            input_hash: 1,
+            build_id: 2,
+            elapsed: Duration::from_micros(1500),
+            answer: "1,2,3".into(),
+        };
+        cache.insert(id, Part::One, cached.clone());
+        cache.insert(
+            id,
+            Part::Two,
+            CachedAnswer {
+                answer: "a\nb".into(),
+                ..cached.clone()
+            },
+        );
+        cache.save().unwrap();
+
+        let cache = AnswerCache::load(&path);
+        assert_eq!(Some("1,2,3"), cache.get(id, Part::One, 1, 2));
+        assert_eq!(None, cache.get(id, Part::One, 3, 2));
+        assert_eq!(None, cache.get(id, Part::One, 1, 3));
+        assert_eq!(None, cache.get(id, Part::Two, 1, 2));
+        assert_eq!(Some(&cached), cache.latest(id, Part::One));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_cache_inputs_and_builds() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-inputs-{}", std::process::id()));
+        let path = dir.join("answers.tsv");
+        let id = PuzzleId { year: 2024, day: 1 };
+        let answer = |input_hash, build_id, answer: &str| CachedAnswer {
+            input_hash,
+            build_id,
+            elapsed: Duration::from_millis(1),
+            answer: answer.into(),
+        };
+
+        // an example and a real input are both kept for the same build
+        let mut cache = AnswerCache::load(&path);
+        cache.insert(id, Part::One, answer(1, 7, "11"));
+        cache.insert(id, Part::One, answer(2, 7, "2000"));
+        cache.insert(id, Part::One, answer(1, 7, "11"));
+        assert_eq!(Some("11"), cache.get(id, Part::One, 1, 7));
+        assert_eq!(Some("2000"), cache.get(id, Part::One, 2, 7));
+        assert_eq!(
+            Some("11"),
+            cache.latest(id, Part::One).map(|c| c.answer.as_str())
+        );
+
+        cache.save().unwrap();
+        let mut cache = AnswerCache::load(&path);
+        assert_eq!(Some("2000"), cache.get(id, Part::One, 2, 7));
+        assert_eq!(
+            Some("11"),
+            cache.latest(id, Part::One).map(|c| c.answer.as_str())
+        );
+
+        // a new build drops the answers the old one computed
+        cache.insert(id, Part::One, answer(2, 8, "2000"));
+        assert_eq!(None, cache.get(id, Part::One, 1, 7));
+        assert_eq!(None, cache.get(id, Part::One, 2, 7));
+        assert_eq!(Some("2000"), cache.get(id, Part::One, 2, 8));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
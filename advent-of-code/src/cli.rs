@@ -0,0 +1,695 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::answers::{self, Answers};
+use crate::cache::{self, AnswerCache, CachedAnswer};
+use crate::profile;
+use crate::progress::{self, Update};
+use crate::registry::{self, DEFAULT_IMPL, Part, Puzzle, PuzzleId, Tag};
+use crate::trace::{self, Event, Level};
+use crate::{completions, dot, input, report};
+use std::env;
+use std::io::{IsTerminal, Write};
+use std::time::Instant;
+
+const USAGE: &str = "\
+advent-of-code
+Compute solutions to Advent of Code problems
+
+Usage: advent-of-code [OPTION] PUZZLE [INPUT]
+       advent-of-code [OPTION] YEAR
+       advent-of-code [OPTION] identify INPUT
+       advent-of-code list [--year YEAR] [--tag TAG]
+       advent-of-code report [--html]
+       advent-of-code serve [--port PORT]
+       advent-of-code [OPTION] compare-impls PUZZLE [INPUT]
+       advent-of-code completions SHELL
+
+Commands:
+    identify    Guess which registered puzzle INPUT belongs to
+    list        List registered puzzles, whether their input is present, and how many
+                answers are recorded in answers.txt or inputs/answers.txt.
+                --year and --tag limit the list to one year or one tag.
+    report      Print a Markdown calendar of each year's puzzles with the stars earned
+                according to the answers file and the latest timings from the answer
+                cache. --html prints a standalone HTML page instead.
+    serve       Serve solutions over HTTP on 127.0.0.1:PORT, 8080 by default.
+                GET /puzzles lists the registered puzzles and
+                POST /solve/YEAR/DAY/PART solves a puzzle using the request body as input.
+    compare-impls
+                Run every implementation of each part of PUZZLE, check that their answers
+                agree, and compare how long they took.
+    completions Print a completion script for SHELL, one of bash, zsh, or fish.
+                Puzzle ids are completed from the registered puzzles and the inputs
+                directory.
+
+Arguments:
+    PUZZLE  The puzzle to run. Valid values are dates in the format YYYY-12-DD.
+            The year YYYY is in 2015-2024.
+            The day DD is 01-25. The zero prefix is required.
+    YEAR    Run every registered puzzle for the year YYYY, reading each input from its
+            default path.
+    INPUT   Path to the puzzle input.
+            If stdin is preferred, then -- can be passed to indicate that.
+            If no input is provided, then the path ./inputs/PUZZLE.txt where PUZZLE is the
+            provided puzzle argument.
+
+Options:
+    -h, --help      Print this usage message and exit
+    -V, --version   Print version information and exit
+    -v, --verbose   Print what the solutions report while running to stderr.
+                    -vv also prints intermediate state such as grids.
+    --raw           Pass the input to the solution as-is, without normalizing line endings,
+                    trailing blank lines, or a byte order mark
+    --cached        Reuse answers computed by this same build for the same input, and record
+                    new answers in $XDG_CACHE_HOME/advent-of-code/answers.tsv
+    --profile[=FILE]
+                    Print how long each profiled phase of the solutions took to stderr, or
+                    write it to FILE in the folded stack format used by flamegraph tools
+    --impl NAME     Solve with the implementation NAME instead of the default one, skipping
+                    parts without it
+    --dot FILE      Write the graphs that solutions build, such as the network of 2024-12-23,
+                    to FILE in the DOT language read by Graphviz, skipping the answer cache
+";
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The answer cache used for this run and the build its answers are keyed on.
+struct CacheContext {
+    answers: AnswerCache,
+    build_id: u64,
+}
+
+/// Where the profile of each puzzle run goes.
+enum ProfileOutput {
+    /// Print a report of the spans to stderr.
+    Report,
+    /// Append the spans in the folded stack format to a file.
+    Folded(std::fs::File),
+}
+
+/// How puzzles are run, according to the command line options.
+struct Runner {
+    raw: bool,
+    cache: Option<CacheContext>,
+    profile: Option<ProfileOutput>,
+    /// The implementation to solve with, or the default one for `None`.
+    implementation: Option<String>,
+    /// Where the graphs exported by the solutions are written.
+    dot: Option<std::fs::File>,
+}
+
+pub fn main() {
+    if env::args().any(|x| x == "-V" || x == "--version") {
+        println!("Version: {VERSION}");
+        std::process::exit(0);
+    }
+
+    if env::args().any(|x| x == "-h" || x == "--help") {
+        println!("{USAGE}");
+        std::process::exit(0);
+    }
+
+    let raw = env::args().any(|x| x == "--raw");
+    let cached = env::args().any(|x| x == "--cached");
+    let profile = env::args().find_map(|x| match x.split_once('=') {
+        _ if x == "--profile" => Some(None),
+        Some(("--profile", path)) => Some(Some(path.to_string())),
+        _ => None,
+    });
+    let verbosity: usize = env::args()
+        .map(|x| match x.as_str() {
+            "-v" | "--verbose" => 1,
+            "-vv" => 2,
+            _ => 0,
+        })
+        .sum();
+    let implementation = option_value("--impl", "an implementation name");
+    let dot = option_value("--dot", "a file to write to");
+
+    let mut option_value = false;
+    let mut args = env::args().skip(1).filter(move |x| {
+        if std::mem::take(&mut option_value) {
+            return false;
+        }
+        option_value = x == "--impl" || x == "--dot";
+
+        !option_value
+            && !matches!(
+                x.as_str(),
+                "--raw" | "--cached" | "--profile" | "-v" | "-vv" | "--verbose"
+            )
+            && !x.starts_with("--profile=")
+    });
+
+    // a live progress line would only garble output that isn't going to a terminal
+    if std::io::stdout().is_terminal() && std::io::stderr().is_terminal() {
+        progress::set_sink(progress::UPDATE_INTERVAL, render_progress);
+    }
+
+    if verbosity > 0 {
+        let level = if verbosity == 1 {
+            Level::Info
+        } else {
+            Level::Debug
+        };
+        trace::set_level(Some(level));
+        trace::set_sink(render_event);
+    }
+
+    let Some(puzzle) = args.next() else {
+        eprintln!("{USAGE}");
+        eprintln!("Missing 'PUZZLE' argument");
+        std::process::exit(1);
+    };
+
+    if puzzle == "identify" {
+        let Some(file_arg) = args.next() else {
+            eprintln!("{USAGE}");
+            eprintln!("Missing 'INPUT' argument");
+            std::process::exit(1);
+        };
+
+        let contents = read_input(&[file_arg], raw);
+        identify(&contents);
+        return;
+    }
+
+    if puzzle == "list" {
+        list(args);
+        return;
+    }
+
+    if puzzle == "report" {
+        report(args);
+        return;
+    }
+
+    if puzzle == "serve" {
+        serve(args);
+        return;
+    }
+
+    if puzzle == "completions" {
+        completions(args);
+        return;
+    }
+
+    if puzzle == "compare-impls" {
+        compare_impls(args, raw);
+        return;
+    }
+
+    let mut runner = Runner {
+        raw,
+        cache: if cached { load_cache() } else { None },
+        profile: profile.map(open_profile),
+        implementation,
+        dot: dot.map(create_dot),
+    };
+
+    if let Ok(year) = puzzle.parse::<u16>() {
+        runner.run_year(year);
+    } else {
+        let files = match args.next() {
+            Some(file_arg) => vec![file_arg],
+            None => default_inputs(&puzzle),
+        };
+
+        let contents = read_input(&files, raw);
+        runner.run(&puzzle, &contents);
+    }
+}
+
+/// The value following the option `name`, exiting if it's the last argument.
+fn option_value(name: &str, expected: &str) -> Option<String> {
+    if !env::args().any(|x| x == name) {
+        return None;
+    }
+
+    let Some(value) = env::args().skip_while(|x| x != name).nth(1) else {
+        eprintln!("{USAGE}");
+        eprintln!("{name} expects {expected}");
+        std::process::exit(1);
+    };
+    Some(value)
+}
+
+fn render_event(event: &Event) {
+    let target = event.target.strip_prefix("aoc::").unwrap_or(event.target);
+    let message = event.message.to_string();
+    let fields: String = event
+        .fields
+        .iter()
+        .map(|(name, value)| format!(" {name}={value}"))
+        .collect();
+
+    // multi-line messages are usually renderings, so start them on their own line
+    if message.contains('\n') {
+        eprintln!(
+            "[{}] {target}:{fields}\n{}",
+            event.level,
+            message.trim_end()
+        );
+    } else {
+        eprintln!("[{}] {target}: {message}{fields}", event.level);
+    }
+}
+
+fn render_progress(update: &Update) {
+    // \r returns to the start of the line and \x1b[2K clears it
+    if update.finished {
+        eprint!("\r\x1b[2K");
+        return;
+    }
+
+    let mut line = format!("{}: {}", update.label, update.done);
+    if let Some(total) = update.total {
+        line += &format!("/{total}");
+    }
+    if let Some(fraction) = update.fraction() {
+        line += &format!(" ({:.1}%)", 100.0 * fraction);
+    }
+    eprint!("\r\x1b[2K{line}");
+}
+
+fn default_inputs(puzzle: &str) -> Vec<String> {
+    vec![
+        format!("{puzzle}.txt"),
+        format!("inputs/{puzzle}.txt"),
+        format!("advent-of-code-2024/inputs/{puzzle}.txt"),
+    ]
+}
+
+fn load_cache() -> Option<CacheContext> {
+    let Some(path) = cache::default_path() else {
+        eprintln!("Warning: no cache directory found, answers will not be cached");
+        return None;
+    };
+
+    let Some(build_id) = cache::build_id() else {
+        eprintln!("Warning: failed to identify this build, answers will not be cached");
+        return None;
+    };
+
+    let answers = AnswerCache::load(&path);
+    Some(CacheContext { answers, build_id })
+}
+
+fn open_profile(path: Option<String>) -> ProfileOutput {
+    let Some(path) = path else {
+        return ProfileOutput::Report;
+    };
+
+    match std::fs::File::create(&path) {
+        Ok(file) => ProfileOutput::Folded(file),
+        Err(e) => {
+            eprintln!("Failed to create profile file {path}: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn create_dot(path: String) -> std::fs::File {
+    match std::fs::File::create(&path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create DOT file {path}: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Reads the first of `files` that exists, where `--` stands for stdin.
+fn read_first(files: &[String]) -> Option<String> {
+    files
+        .iter()
+        .filter_map(|file| match file.as_str() {
+            "--" => std::io::read_to_string(std::io::stdin()).ok(),
+            path => std::fs::read_to_string(path).ok(),
+        })
+        .next()
+}
+
+fn prepare_input(contents: String, raw: bool) -> String {
+    if raw {
+        contents
+    } else {
+        input::normalize(&contents)
+    }
+}
+
+/// Reads the first of `files` that exists, exiting the program if none of them could be read.
+fn read_input(files: &[String], raw: bool) -> String {
+    let Some(contents) = read_first(files) else {
+        let file_input_name = files
+            .iter()
+            .map(|file| if file == "--" { "STDIN" } else { file.as_str() })
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!("Failed to read file: {file_input_name}");
+        std::process::exit(1);
+    };
+
+    prepare_input(contents, raw)
+}
+
+fn identify(input: &str) {
+    let candidates = registry::identify(input);
+    if candidates.is_empty() {
+        eprintln!("Input does not look like any registered puzzle");
+        std::process::exit(1);
+    }
+
+    for id in candidates {
+        println!("{id}");
+    }
+}
+
+fn list(mut args: impl Iterator<Item = String>) {
+    let mut year = None;
+    let mut tag = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => {
+                let Some(y) = args.next().and_then(|y| y.parse::<u16>().ok()) else {
+                    eprintln!("{USAGE}");
+                    eprintln!("--year expects a year such as 2024");
+                    std::process::exit(1);
+                };
+                year = Some(y);
+            }
+            "--tag" => match args.next().unwrap_or_default().parse::<Tag>() {
+                Ok(t) => tag = Some(t),
+                Err(e) => {
+                    let tags: Vec<&str> = Tag::all().into_iter().map(Tag::name).collect();
+                    eprintln!("{e}, expected one of: {}", tags.join(", "));
+                    std::process::exit(1);
+                }
+            },
+            _ => {
+                eprintln!("{USAGE}");
+                eprintln!("Unexpected argument '{arg}'");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let answers = Answers::load(&answers::default_paths());
+    let rows: Vec<[String; 6]> = registry::all()
+        .filter(|p| year.is_none_or(|y| p.id.year == y))
+        .filter(|p| tag.is_none_or(|t| p.tags.contains(&t)))
+        .map(|p| {
+            let tags: Vec<&str> = p.tags.iter().map(|t| t.name()).collect();
+            let has_input = default_inputs(&p.id.to_string())
+                .iter()
+                .any(|f| std::path::Path::new(f).exists());
+
+            [
+                p.id.to_string(),
+                p.title.to_string(),
+                tags.join(","),
+                p.modules.join(","),
+                if has_input { "yes" } else { "no" }.to_string(),
+                format!("{}/2", answers.count(p.id)),
+            ]
+        })
+        .collect();
+
+    let header = ["PUZZLE", "TITLE", "TAGS", "MODULES", "INPUT", "ANSWERS"];
+    print_table(header, &rows);
+}
+
+/// Prints `rows` under `header`, with each column as wide as its widest cell.
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let header = header.map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    }
+}
+
+fn report(args: impl Iterator<Item = String>) {
+    let mut html = false;
+    for arg in args {
+        if arg == "--html" {
+            html = true;
+        } else {
+            eprintln!("{USAGE}");
+            eprintln!("Unexpected argument '{arg}'");
+            std::process::exit(1);
+        }
+    }
+
+    let answers = Answers::load(&answers::default_paths());
+    let cache = cache::default_path()
+        .map(|path| AnswerCache::load(&path))
+        .unwrap_or_default();
+
+    if html {
+        print!("{}", report::html(&answers, &cache));
+    } else {
+        print!("{}", report::markdown(&answers, &cache));
+    }
+}
+
+fn serve(mut args: impl Iterator<Item = String>) {
+    let mut port: u16 = 8080;
+    while let Some(arg) = args.next() {
+        if arg == "--port"
+            && let Some(p) = args.next().and_then(|p| p.parse().ok())
+        {
+            port = p;
+        } else {
+            eprintln!("{USAGE}");
+            eprintln!("Unexpected argument '{arg}'");
+            std::process::exit(1);
+        }
+    }
+
+    let listener = match std::net::TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Failed to listen on port {port}: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    eprintln!("Listening on http://127.0.0.1:{port}");
+    if let Err(e) = crate::serve::serve(&listener) {
+        eprintln!("Server stopped: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn compare_impls(mut args: impl Iterator<Item = String>, raw: bool) {
+    let Some(puzzle_arg) = args.next() else {
+        eprintln!("{USAGE}");
+        eprintln!("Missing 'PUZZLE' argument");
+        std::process::exit(1);
+    };
+
+    let Some(puzzle) = puzzle_arg.parse::<PuzzleId>().ok().and_then(registry::find) else {
+        println!("Unrecognized PUZZLE '{puzzle_arg}'");
+        std::process::exit(1);
+    };
+
+    let files = match args.next() {
+        Some(file_arg) => vec![file_arg],
+        None => default_inputs(&puzzle_arg),
+    };
+    let input = read_input(&files, raw);
+
+    let mut rows = vec![];
+    let mut disagreeing = vec![];
+    for part in Part::all() {
+        let mut default = None;
+        for (name, solve) in puzzle.implementations(part) {
+            let start = Instant::now();
+            let answer = solve(&input);
+            let elapsed = start.elapsed();
+
+            // the default implementation comes first, and the others are compared to it
+            let (default_answer, default_elapsed) =
+                default.get_or_insert_with(|| (answer.clone(), elapsed));
+            if answer != *default_answer && !disagreeing.contains(&part) {
+                disagreeing.push(part);
+            }
+
+            rows.push([
+                part.to_string(),
+                name.to_string(),
+                answer,
+                format!("{elapsed:.2?}"),
+                format!(
+                    "{:.2}x",
+                    elapsed.as_secs_f64() / default_elapsed.as_secs_f64()
+                ),
+            ]);
+        }
+    }
+
+    print_table(["PART", "IMPL", "ANSWER", "TIME", "RELATIVE"], &rows);
+
+    for part in &disagreeing {
+        eprintln!("Implementations of part {part} disagree");
+    }
+    if !disagreeing.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn completions(mut args: impl Iterator<Item = String>) {
+    let shell = args.next().unwrap_or_default();
+    let Some(script) = completions::script(&shell) else {
+        eprintln!("{USAGE}");
+        eprintln!(
+            "Unknown SHELL '{shell}', expected one of: {}",
+            completions::SHELLS.join(", ")
+        );
+        std::process::exit(1);
+    };
+
+    print!("{script}");
+}
+
+impl Runner {
+    fn run_puzzle(&mut self, puzzle: &Puzzle, input: &str) {
+        if !(puzzle.looks_like_input)(input) {
+            eprintln!("Warning: input does not look like {}", puzzle.id);
+        }
+
+        profile::set_enabled(self.profile.is_some());
+        dot::set_enabled(self.dot.is_some());
+        let input_hash = cache::hash(input.as_bytes());
+        let name = self.implementation.as_deref().unwrap_or(DEFAULT_IMPL);
+        // the cache only holds answers from the default implementations, and a cached answer
+        // skips the solution along with the graphs it would export
+        let use_cache = name == DEFAULT_IMPL && self.dot.is_none();
+        for part in Part::all() {
+            let Some(solve) = puzzle.implementation(part, name) else {
+                eprintln!("Skipping part {part}: no implementation named '{name}'");
+                continue;
+            };
+
+            let cache = self.cache.as_mut().filter(|_| use_cache);
+            let cached = cache
+                .as_ref()
+                .and_then(|c| c.answers.get(puzzle.id, part, input_hash, c.build_id));
+            if let Some(answer) = cached {
+                println!("Part {part} Solution: {answer} (cached)");
+                continue;
+            }
+
+            let span = profile::span(match part {
+                Part::One => "part 1",
+                Part::Two => "part 2",
+            });
+            let start = Instant::now();
+            let answer = solve(input);
+            let elapsed = start.elapsed();
+            drop(span);
+            println!("Part {part} Solution: {answer}");
+
+            if let Some(c) = cache {
+                let cached = CachedAnswer {
+                    input_hash,
+                    build_id: c.build_id,
+                    elapsed,
+                    answer,
+                };
+                c.answers.insert(puzzle.id, part, cached);
+
+                // save after every answer so a later panicking solution doesn't lose them
+                if let Err(e) = c.answers.save() {
+                    eprintln!("Warning: failed to save answer cache: {e}");
+                }
+            }
+        }
+
+        self.write_profile(puzzle.id);
+        self.write_dot();
+    }
+
+    fn write_dot(&mut self) {
+        let graphs = dot::take();
+        let Some(file) = &mut self.dot else {
+            return;
+        };
+
+        for graph in graphs {
+            if let Err(e) = file.write_all(graph.as_bytes()) {
+                eprintln!("Warning: failed to write graph: {e}");
+            }
+        }
+    }
+
+    fn write_profile(&mut self, id: PuzzleId) {
+        let profile = profile::take();
+        match &mut self.profile {
+            None => {}
+            Some(_) if profile.is_empty() => {}
+            Some(ProfileOutput::Report) => eprint!("{}", profile.report()),
+            Some(ProfileOutput::Folded(file)) => {
+                let folded = profile.folded(Some(&id.to_string()));
+                if let Err(e) = file.write_all(folded.as_bytes()) {
+                    eprintln!("Warning: failed to write profile: {e}");
+                }
+            }
+        }
+    }
+
+    fn run(&mut self, puzzle: &str, input: &str) {
+        let Some(puzzle) = puzzle.parse::<PuzzleId>().ok().and_then(registry::find) else {
+            println!("Unrecognized PUZZLE '{puzzle}'");
+            std::process::exit(1);
+        };
+
+        self.run_puzzle(puzzle, input);
+    }
+
+    fn run_year(&mut self, year: u16) {
+        let puzzles: Vec<&Puzzle> = registry::year(year).collect();
+        if puzzles.is_empty() {
+            println!("Unrecognized PUZZLE '{year}'");
+            std::process::exit(1);
+        }
+
+        for puzzle in puzzles {
+            println!("{}", puzzle.id);
+            let Some(contents) = read_first(&default_inputs(&puzzle.id.to_string())) else {
+                eprintln!("Skipping {}: no input found", puzzle.id);
+                continue;
+            };
+
+            let contents = prepare_input(contents, self.raw);
+            self.run_puzzle(puzzle, &contents);
+        }
+    }
+}
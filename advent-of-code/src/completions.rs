@@ -0,0 +1,435 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Shell completion scripts for the `advent-of-code` command.
+//!
+//! Puzzle ids are taken from the registry when the script is generated, and from the
+//! `inputs` directory of wherever the shell is when completing.
+
+use crate::registry::{self, DEFAULT_IMPL, Tag};
+
+/// The shells a completion script can be generated for.
+pub const SHELLS: [&str; 3] = ["bash", "zsh", "fish"];
+
+const COMMANDS: [(&str, &str); 6] = [
+    ("identify", "Guess which puzzle an input belongs to"),
+    ("list", "List registered puzzles"),
+    ("report", "Print a calendar of stars and timings"),
+    ("serve", "Serve solutions over HTTP"),
+    ("compare-impls", "Compare every implementation of a puzzle"),
+    ("completions", "Print a shell completion script"),
+];
+
+/// Commands whose arguments are all options, so no files are completed after them.
+const OPTION_COMMANDS: [&str; 3] = ["list", "report", "serve"];
+
+struct Opt {
+    short: Option<char>,
+    long: &'static str,
+    /// The command the option belongs to, or `None` for options taken before any command.
+    command: Option<&'static str>,
+    takes_value: bool,
+    description: &'static str,
+}
+
+const OPTIONS: [Opt; 12] = [
+    Opt {
+        short: Some('h'),
+        long: "help",
+        command: None,
+        takes_value: false,
+        description: "Print the usage message and exit",
+    },
+    Opt {
+        short: Some('V'),
+        long: "version",
+        command: None,
+        takes_value: false,
+        description: "Print version information and exit",
+    },
+    Opt {
+        short: Some('v'),
+        long: "verbose",
+        command: None,
+        takes_value: false,
+        description: "Print what the solutions report, -vv for more",
+    },
+    Opt {
+        short: None,
+        long: "raw",
+        command: None,
+        takes_value: false,
+        description: "Pass the input to the solution as-is",
+    },
+    Opt {
+        short: None,
+        long: "cached",
+        command: None,
+        takes_value: false,
+        description: "Reuse and record answers in the answer cache",
+    },
+    Opt {
+        short: None,
+        long: "profile",
+        command: None,
+        takes_value: false,
+        description: "Print how long each phase of the solutions took",
+    },
+    Opt {
+        short: None,
+        long: "impl",
+        command: None,
+        takes_value: true,
+        description: "Solve with another implementation",
+    },
+    Opt {
+        short: None,
+        long: "dot",
+        command: None,
+        takes_value: true,
+        description: "Write the graphs the solutions build to a DOT file",
+    },
+    Opt {
+        short: None,
+        long: "year",
+        command: Some("list"),
+        takes_value: true,
+        description: "Only list puzzles from this year",
+    },
+    Opt {
+        short: None,
+        long: "tag",
+        command: Some("list"),
+        takes_value: true,
+        description: "Only list puzzles with this tag",
+    },
+    Opt {
+        short: None,
+        long: "html",
+        command: Some("report"),
+        takes_value: false,
+        description: "Print a standalone HTML page",
+    },
+    Opt {
+        short: None,
+        long: "port",
+        command: Some("serve"),
+        takes_value: true,
+        description: "Port to listen on",
+    },
+];
+
+/// Prints the ids of the puzzle inputs in `./inputs`, one per line.
+const INPUTS: &str = r"ls inputs 2>/dev/null | sed -n 's/^\([0-9]\{4\}-12-[0-9]\{2\}\)\.txt$/\1/p'";
+
+const BASH: &str = r#"# bash completion for advent-of-code
+
+_advent_of_code() {
+    local cur prev
+    cur="${COMP_WORDS[COMP_CWORD]}"
+    prev="${COMP_WORDS[COMP_CWORD-1]}"
+
+    case "$prev" in
+        --year) COMPREPLY=($(compgen -W "@YEARS@" -- "$cur")); return ;;
+        --tag) COMPREPLY=($(compgen -W "@TAGS@" -- "$cur")); return ;;
+        --impl) COMPREPLY=($(compgen -W "@IMPLS@" -- "$cur")); return ;;
+        --dot) COMPREPLY=($(compgen -f -- "$cur")); return ;;
+        --port) return ;;
+    esac
+
+    local i command=""
+    for ((i = 1; i < COMP_CWORD; i++)); do
+        case "${COMP_WORDS[i]}" in
+            --impl | --dot) ((i++)) ;;
+            -*) ;;
+            *) command="${COMP_WORDS[i]}"; break ;;
+        esac
+    done
+
+    if [[ "$cur" == -* ]]; then
+        local options
+        case "$command" in
+@COMMAND_OPTIONS@
+        esac
+        COMPREPLY=($(compgen -W "$options" -- "$cur"))
+        return
+    fi
+
+    case "$command" in
+        "")
+            local inputs
+            inputs="$(@INPUTS@)"
+            COMPREPLY=($(compgen -W "@COMMANDS@ @YEARS@ @PUZZLES@ $inputs" -- "$cur"))
+            ;;
+        completions) COMPREPLY=($(compgen -W "@SHELLS@" -- "$cur")) ;;
+        compare-impls) COMPREPLY=($(compgen -W "@PUZZLES@" -- "$cur") $(compgen -f -- "$cur")) ;;
+        @OPTION_COMMANDS@) ;;
+        *) COMPREPLY=($(compgen -f -- "$cur")) ;;
+    esac
+}
+
+complete -F _advent_of_code advent-of-code
+"#;
+
+const ZSH: &str = r#"#compdef advent-of-code
+
+_advent_of_code() {
+    local -a commands years tags puzzles inputs
+    commands=(@COMMANDS@)
+    years=(@YEARS@)
+    tags=(@TAGS@)
+    puzzles=(@PUZZLES@)
+    inputs=(${(f)"$(@INPUTS@)"})
+
+    case "$words[CURRENT-1]" in
+        --year) compadd -a years; return ;;
+        --tag) compadd -a tags; return ;;
+        --impl) compadd @IMPLS@; return ;;
+        --dot) _files; return ;;
+        --port) return ;;
+    esac
+
+    local i command=""
+    for ((i = 2; i < CURRENT; i++)); do
+        case "$words[i]" in
+            --impl | --dot) ((i++)) ;;
+            -*) ;;
+            *) command="$words[i]"; break ;;
+        esac
+    done
+
+    if [[ "$PREFIX" == -* ]]; then
+        local options
+        case "$command" in
+@COMMAND_OPTIONS@
+        esac
+        compadd ${=options}
+        return
+    fi
+
+    case "$command" in
+        "") compadd -a commands years puzzles inputs ;;
+        completions) compadd @SHELLS@ ;;
+        compare-impls) compadd -a puzzles; _files ;;
+        @OPTION_COMMANDS@) ;;
+        *) _files ;;
+    esac
+}
+
+if [[ "$zsh_eval_context[-1]" == loadautofunc ]]; then
+    _advent_of_code "$@"
+else
+    compdef _advent_of_code advent-of-code
+fi
+"#;
+
+const FISH: &str = r"# fish completion for advent-of-code
+
+function __advent_of_code_inputs
+    @INPUTS@
+end
+
+complete -c advent-of-code -f
+";
+
+/// Generates the completion script for `shell`, one of [`SHELLS`].
+#[must_use]
+pub fn script(shell: &str) -> Option<String> {
+    let template = match shell {
+        "bash" => BASH,
+        "zsh" => ZSH,
+        "fish" => FISH,
+        _ => return None,
+    };
+
+    let mut script = template
+        .replace("@COMMANDS@", &COMMANDS.map(|(name, _)| name).join(" "))
+        .replace("@COMMAND_OPTIONS@", &command_options())
+        .replace("@OPTION_COMMANDS@", &OPTION_COMMANDS.join(" | "))
+        .replace("@YEARS@", &years().join(" "))
+        .replace("@TAGS@", &tags().join(" "))
+        .replace("@IMPLS@", &impls().join(" "))
+        .replace("@PUZZLES@", &puzzles().join(" "))
+        .replace("@SHELLS@", &SHELLS.join(" "))
+        .replace("@INPUTS@", INPUTS);
+
+    if shell == "fish" {
+        script.push_str(&fish_completions());
+    }
+
+    Some(script)
+}
+
+/// A `case` arm per command setting `options` to the options that command takes.
+fn command_options() -> String {
+    let arm = |pattern: &str, command: Option<&str>| {
+        let options: Vec<String> = OPTIONS
+            .iter()
+            .filter(|o| o.command == command)
+            .flat_map(|o| {
+                let short = o.short.map(|s| format!("-{s}"));
+                short.into_iter().chain([format!("--{}", o.long)])
+            })
+            .collect();
+        format!(
+            "            {pattern}) options=\"{}\" ;;",
+            options.join(" ")
+        )
+    };
+
+    OPTION_COMMANDS
+        .iter()
+        .map(|&command| arm(command, Some(command)))
+        .chain([arm("*", None)])
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn fish_completions() -> String {
+    let mut lines = Vec::new();
+    let complete = "complete -c advent-of-code";
+    let needs_command = "-n __fish_use_subcommand";
+
+    for o in &OPTIONS {
+        let mut line = complete.to_string();
+        match o.command {
+            Some(command) => line += &format!(" -n '__fish_seen_subcommand_from {command}'"),
+            None => line += &format!(" {needs_command}"),
+        }
+        if let Some(short) = o.short {
+            line += &format!(" -s {short}");
+        }
+        line += &format!(" -l {}", o.long);
+        match (o.takes_value, o.long) {
+            (true, "dot") => line += " -r -F",
+            (true, _) => line += " -x",
+            (false, _) => {}
+        }
+        match o.long {
+            "year" => line += &format!(" -a '{}'", years().join(" ")),
+            "tag" => line += &format!(" -a '{}'", tags().join(" ")),
+            "impl" => line += &format!(" -a '{}'", impls().join(" ")),
+            _ => {}
+        }
+        line += &format!(" -d '{}'", o.description);
+        lines.push(line);
+    }
+
+    for (name, description) in COMMANDS {
+        lines.push(format!(
+            "{complete} {needs_command} -a {name} -d '{description}'"
+        ));
+    }
+
+    lines.push(format!(
+        "{complete} {needs_command} -a '{}' -d 'Run every puzzle of the year'",
+        years().join(" ")
+    ));
+    lines.push(format!(
+        "{complete} {needs_command} -a '{}' -d Puzzle",
+        puzzles().join(" ")
+    ));
+    lines.push(format!(
+        "{complete} {needs_command} -a '(__advent_of_code_inputs)' -d 'Puzzle input'"
+    ));
+    lines.push(format!(
+        "{complete} -n '__fish_seen_subcommand_from completions' -a '{}'",
+        SHELLS.join(" ")
+    ));
+    lines.push(format!(
+        "{complete} -n '__fish_seen_subcommand_from compare-impls' -a '{}'",
+        puzzles().join(" ")
+    ));
+
+    // the INPUT argument of a puzzle or identify is a path
+    let mut commands: Vec<&str> = OPTION_COMMANDS.to_vec();
+    commands.push("completions");
+    lines.push(format!(
+        "{complete} -n 'not __fish_use_subcommand; and not __fish_seen_subcommand_from {}' -F",
+        commands.join(" ")
+    ));
+
+    lines.join("\n") + "\n"
+}
+
+fn years() -> Vec<String> {
+    let mut years: Vec<u16> = registry::all().map(|p| p.id.year).collect();
+    years.dedup();
+    years.iter().map(u16::to_string).collect()
+}
+
+fn tags() -> Vec<&'static str> {
+    Tag::all().into_iter().map(Tag::name).collect()
+}
+
+/// The names of every implementation of any puzzle.
+fn impls() -> Vec<&'static str> {
+    let mut names = vec![DEFAULT_IMPL];
+    for name in registry::all().flat_map(|p| p.alternatives.iter().map(|i| i.name)) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+fn puzzles() -> Vec<String> {
+    registry::all().map(|p| p.id.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    #[test]
+    fn test_script() {
+        assert!(script("powershell").is_none());
+
+        for shell in SHELLS {
+            let script = script(shell).unwrap();
+            let placeholder = script
+                .match_indices('@')
+                .any(|(i, _)| script[i + 1..].starts_with(|c: char| c.is_ascii_uppercase()));
+            assert!(!placeholder, "{shell} script has a placeholder left");
+            for (command, _) in COMMANDS {
+                assert!(
+                    script.contains(command),
+                    "{shell} script is missing {command}"
+                );
+            }
+            for id in puzzles() {
+                assert!(script.contains(&id), "{shell} script is missing {id}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_bash_syntax() {
+        // bash isn't necessarily installed where the tests run
+        let Ok(mut bash) = Command::new("bash").arg("-n").stdin(Stdio::piped()).spawn() else {
+            return;
+        };
+
+        let mut stdin = bash.stdin.take().unwrap();
+        stdin.write_all(script("bash").unwrap().as_bytes()).unwrap();
+        drop(stdin);
+        assert!(bash.wait().unwrap().success());
+    }
+}
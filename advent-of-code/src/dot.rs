@@ -0,0 +1,75 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Graphviz renderings of the graphs solutions build.
+//!
+//! A solution passes a function rendering its graph in the DOT language to [`export`], which
+//! only calls it while exporting is enabled with [`set_enabled`], since rendering a real input
+//! can take longer than solving it. Exports are kept per thread until they're collected with
+//! [`take`].
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static EXPORTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records the graph drawn by `render`, if exporting is enabled.
+pub fn export(render: impl FnOnce() -> String) {
+    if enabled() {
+        let dot = render();
+        EXPORTS.with_borrow_mut(|exports| exports.push(dot));
+    }
+}
+
+/// Takes the graphs exported on this thread so far, in the order they were exported.
+#[must_use]
+pub fn take() -> Vec<String> {
+    EXPORTS.take()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export() {
+        set_enabled(false);
+        export(|| unreachable!("rendered while disabled"));
+        assert!(take().is_empty());
+
+        set_enabled(true);
+        export(|| "graph a {}\n".to_string());
+        export(|| "graph b {}\n".to_string());
+        set_enabled(false);
+        assert_eq!(take(), vec!["graph a {}\n", "graph b {}\n"]);
+        assert!(take().is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! C ABI for the solver library, declared in `include/aoc.h`.
+//!
+//! Both functions write a NUL terminated string into a caller provided buffer.
+//! `out_len` points to the size of that buffer on entry and receives the length of the string,
+//! excluding the NUL, on return.
+//! When the buffer is too small nothing is written and `out_len` receives the size needed.
+
+use crate::{AocError, Part, PuzzleId};
+use std::ffi::c_char;
+
+pub const AOC_OK: i32 = 0;
+pub const AOC_UNKNOWN_PUZZLE: i32 = -1;
+pub const AOC_INVALID_ARGUMENT: i32 = -2;
+pub const AOC_SOLUTION_PANICKED: i32 = -3;
+pub const AOC_BUFFER_TOO_SMALL: i32 = -4;
+
+/// # Safety
+/// `out_buf` must be valid for writes of `*out_len` bytes and `out_len` must be a valid pointer.
+unsafe fn write_out(s: &str, out_buf: *mut c_char, out_len: *mut usize) -> i32 {
+    if out_len.is_null() {
+        return AOC_INVALID_ARGUMENT;
+    }
+
+    // SAFETY: checked for null above, the caller guarantees it is otherwise valid
+    let capacity = unsafe { *out_len };
+    // SAFETY: as above
+    unsafe { *out_len = s.len() };
+
+    if out_buf.is_null() || capacity <= s.len() {
+        // SAFETY: as above
+        unsafe { *out_len = s.len() + 1 };
+        return AOC_BUFFER_TOO_SMALL;
+    }
+
+    // SAFETY: the caller guarantees `out_buf` holds `capacity` bytes, which is more than the
+    // string and its NUL terminator
+    unsafe {
+        std::ptr::copy_nonoverlapping(s.as_ptr(), out_buf.cast::<u8>(), s.len());
+        *out_buf.add(s.len()) = 0;
+    }
+
+    AOC_OK
+}
+
+/// Solves one part of a puzzle, writing the answer into `out_buf`.
+///
+/// Returns `AOC_OK` on success, or one of the negative `AOC_*` error codes.
+///
+/// # Safety
+/// `input` must be valid for reads of `input_len` bytes, `out_buf` must be valid for writes of
+/// `*out_len` bytes, and `out_len` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const c_char,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: *mut usize,
+) -> i32 {
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return AOC_INVALID_ARGUMENT,
+    };
+
+    if input.is_null() {
+        return AOC_INVALID_ARGUMENT;
+    }
+
+    // SAFETY: the caller guarantees `input` points to `input_len` readable bytes
+    let input = unsafe { std::slice::from_raw_parts(input.cast::<u8>(), input_len) };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return AOC_INVALID_ARGUMENT;
+    };
+
+    match crate::solve(PuzzleId { year, day }, part, input) {
+        // SAFETY: the caller upholds the requirements on `out_buf` and `out_len`
+        Ok(answer) => unsafe { write_out(answer.as_str(), out_buf, out_len) },
+        Err(AocError::UnknownPuzzle(_)) => AOC_UNKNOWN_PUZZLE,
+        Err(AocError::SolutionPanicked { .. }) => AOC_SOLUTION_PANICKED,
+    }
+}
+
+/// Writes the registered puzzles into `out_buf` as `YYYY-12-DD` dates, one per line.
+///
+/// Returns `AOC_OK` on success, or one of the negative `AOC_*` error codes.
+///
+/// # Safety
+/// `out_buf` must be valid for writes of `*out_len` bytes and `out_len` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_list_puzzles(out_buf: *mut c_char, out_len: *mut usize) -> i32 {
+    let puzzles: String = crate::puzzles().map(|p| format!("{}\n", p.id)).collect();
+
+    // SAFETY: the caller upholds the requirements on `out_buf` and `out_len`
+    unsafe { write_out(&puzzles, out_buf, out_len) }
+}
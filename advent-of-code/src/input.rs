@@ -0,0 +1,80 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+/// Normalizes puzzle input so that solutions see the same text regardless of how it was saved.
+///
+/// A leading byte order mark is removed, CRLF line endings become LF, trailing blank lines and
+/// whitespace are trimmed, and the result ends with exactly one newline.
+/// Empty input stays empty.
+///
+/// Whitespace at the end of every other line is deliberately kept, since some puzzles pad their
+/// lines with it to line up columns.
+#[must_use]
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = input.replace("\r\n", "\n");
+
+    let len = normalized.trim_end().len();
+    normalized.truncate(len);
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let tests = vec![
+            ("", ""),
+            ("\n\n", ""),
+            ("abc", "abc\n"),
+            ("abc\n", "abc\n"),
+            ("abc\n\n\n", "abc\n"),
+            ("abc  \n \n", "abc\n"),
+            ("a\r\nb\r\n", "a\nb\n"),
+            ("a\r\n\r\nb", "a\n\nb\n"),
+            ("\u{feff}abc\r\n", "abc\n"),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(expected, normalize(input), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_saved_input() {
+        // an input with padded lines, and the same input as editors and downloads save it
+        let input = "ab \n  c\n\nd  e\n";
+        let tests = vec![
+            input.to_string(),
+            input.replace('\n', "\r\n"),
+            input.trim_end().to_string(),
+            format!("\u{feff}{input}\n \n"),
+            format!("{}\r\n\r\n", input.trim_end().replace('\n', "\r\n")),
+        ];
+
+        for saved in tests {
+            assert_eq!(normalize(&saved), "ab \n  c\n\nd  e\n", "{saved:?}");
+        }
+    }
+}
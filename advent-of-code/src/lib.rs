@@ -16,6 +16,7 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+#[cfg(feature = "y2024")]
 pub mod y2024 {
     pub mod day01;
     pub mod day02;
@@ -45,136 +46,153 @@ pub mod y2024 {
     pub mod direction;
     pub mod graph;
     pub mod maze;
+    pub mod traversal;
 }
 
+#[cfg(feature = "y2025")]
 pub mod y2025 {
     pub mod day01;
     pub mod day02;
     pub mod day03;
 }
 
-use std::env;
-
-const USAGE: &str = "\
-advent-of-code
-Compute solutions to Advent of Code problems
-
-Usage: advent-of-code [OPTION] PUZZLE [INPUT]
+pub mod answers;
+pub mod cache;
+mod cli;
+pub mod completions;
+pub mod dot;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod input;
+pub mod profile;
+pub mod progress;
+#[cfg(feature = "python")]
+mod python;
+pub mod registry;
+pub mod report;
+pub mod serve;
+pub mod trace;
+
+pub use cli::main;
+pub use registry::{Part, Puzzle, PuzzleId, Tag};
+
+use std::fmt;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// The answer to one part of a puzzle, as it would be submitted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 
-Arguments:
-    PUZZLE  The puzzle to run. Valid values are dates in the format YYYY-12-DD.
-            The year YYYY is in 2015-2024.
-            The day DD is 01-25. The zero prefix is required.
-    INPUT   Path to the puzzle input.
-            If stdin is preferred, then -- can be passed to indicate that.
-            If no input is provided, then the path ./inputs/PUZZLE.txt where PUZZLE is the
-            provided puzzle argument.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
 
-Options:
-    -h, --help      Print this usage message and exit
-    -v, --version   Print version information and exit
-";
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
-const VERSION: &str = env!("CARGO_PKG_VERSION");
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// No solution is registered for the puzzle.
+    UnknownPuzzle(PuzzleId),
+    /// The solution panicked, usually because the input wasn't what it expected.
+    SolutionPanicked {
+        id: PuzzleId,
+        part: Part,
+        message: String,
+    },
+}
 
-pub fn main() {
-    if env::args().any(|x| x == "-v" || x == "--version") {
-        println!("Version: {VERSION}");
-        std::process::exit(0);
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::UnknownPuzzle(id) => write!(f, "no solution registered for {id}"),
+            AocError::SolutionPanicked { id, part, message } => {
+                write!(f, "solution for {id} part {part} panicked: {message}")
+            }
+        }
     }
+}
 
-    if env::args().any(|x| x == "-h" || x == "--help") {
-        println!("{USAGE}");
-        std::process::exit(0);
-    }
+impl std::error::Error for AocError {}
 
-    let mut args = env::args().skip(1);
-
-    let Some(puzzle) = args.next() else {
-        eprintln!("{USAGE}");
-        eprintln!("Missing 'PUZZLE' argument");
-        std::process::exit(1);
-    };
-
-    let defaults = vec![
-        format!("{puzzle}.txt"),
-        format!("inputs/{puzzle}.txt"),
-        format!("advent-of-code-2024/inputs/{puzzle}.txt"),
-    ];
-
-    let file_arg = args.next();
-    let file_input_name = match file_arg.as_deref() {
-        Some("--") => "STDIN".to_string(),
-        Some(p) => p.to_string(),
-        None => defaults.join(", "),
-    };
-
-    let contents = match file_arg.as_deref() {
-        Some("--") => std::io::read_to_string(std::io::stdin()).ok(),
-        Some(p) => std::fs::read_to_string(p).ok(),
-        None => defaults
-            .into_iter()
-            .filter_map(|path| std::fs::read_to_string(path.as_str()).ok())
-            .next(),
-    };
-
-    let Some(contents) = contents else {
-        eprintln!("Failed to read file: {file_input_name}");
-        std::process::exit(1);
-    };
-
-    run(&puzzle, &contents);
+/// Every registered puzzle, in order of date.
+pub fn puzzles() -> impl Iterator<Item = &'static Puzzle> {
+    registry::all()
 }
 
-fn run_puzzle<F, G, A, B>(input: &str, part1: F, part2: G)
-where
-    F: Fn(&str) -> A,
-    G: Fn(&str) -> B,
-    A: ToString,
-    B: ToString,
-{
-    let soln1 = part1(input).to_string();
-    println!("Part 1 Solution: {soln1}");
-
-    let soln2 = part2(input).to_string();
-    println!("Part 2 Solution: {soln2}");
+/// Solves one part of a puzzle.
+///
+/// The input is normalized the same way the command line does by default, see
+/// [`input::normalize`].
+///
+/// ```
+/// use aoc::{Part, PuzzleId};
+///
+/// # #[cfg(feature = "y2024")] {
+/// let id: PuzzleId = "2024-12-01".parse().unwrap();
+/// let answer = aoc::solve(id, Part::One, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+/// assert_eq!("11", answer.as_str());
+/// # }
+/// ```
+///
+/// # Errors
+/// Returns an error if no solution is registered for `id`, or if the solution panics.
+pub fn solve(id: PuzzleId, part: Part, input: &str) -> Result<Answer, AocError> {
+    let puzzle = registry::find(id).ok_or(AocError::UnknownPuzzle(id))?;
+    let input = input::normalize(input);
+
+    catch_unwind(AssertUnwindSafe(|| puzzle.solve(part, &input)))
+        .map(Answer)
+        .map_err(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+
+            AocError::SolutionPanicked { id, part, message }
+        })
 }
 
-fn run(puzzle: &str, input: &str) {
-    match puzzle {
-        // 2024
-        "2024-12-01" => run_puzzle(input, y2024::day01::part1, y2024::day01::part2),
-        "2024-12-02" => run_puzzle(input, y2024::day02::part1, y2024::day02::part2),
-        "2024-12-03" => run_puzzle(input, y2024::day03::part1, y2024::day03::part2),
-        "2024-12-04" => run_puzzle(input, y2024::day04::part1, y2024::day04::part2),
-        "2024-12-05" => run_puzzle(input, y2024::day05::part1, y2024::day05::part2),
-        "2024-12-06" => run_puzzle(input, y2024::day06::part1, y2024::day06::part2),
-        "2024-12-07" => run_puzzle(input, y2024::day07::part1, y2024::day07::part2),
-        "2024-12-08" => run_puzzle(input, y2024::day08::part1, y2024::day08::part2),
-        "2024-12-09" => run_puzzle(input, y2024::day09::part1, y2024::day09::part2),
-        "2024-12-10" => run_puzzle(input, y2024::day10::part1, y2024::day10::part2),
-        "2024-12-11" => run_puzzle(input, y2024::day11::part1, y2024::day11::part2),
-        "2024-12-12" => run_puzzle(input, y2024::day12::part1, y2024::day12::part2),
-        "2024-12-13" => run_puzzle(input, y2024::day13::part1, y2024::day13::part2),
-        "2024-12-14" => run_puzzle(input, y2024::day14::part1, y2024::day14::part2),
-        "2024-12-15" => run_puzzle(input, y2024::day15::part1, y2024::day15::part2),
-        "2024-12-16" => run_puzzle(input, y2024::day16::part1, y2024::day16::part2),
-        "2024-12-17" => run_puzzle(input, y2024::day17::part1, y2024::day17::part2),
-        "2024-12-18" => run_puzzle(input, y2024::day18::part1, y2024::day18::part2),
-        "2024-12-19" => run_puzzle(input, y2024::day19::part1, y2024::day19::part2),
-        "2024-12-20" => run_puzzle(input, y2024::day20::part1, y2024::day20::part2),
-        "2024-12-21" => run_puzzle(input, y2024::day21::part1, y2024::day21::part2),
-        "2024-12-22" => run_puzzle(input, y2024::day22::part1, y2024::day22::part2),
-        "2024-12-23" => run_puzzle(input, y2024::day23::part1, y2024::day23::part2),
-        "2024-12-24" => run_puzzle(input, y2024::day24::part1, y2024::day24::part2),
-        "2024-12-25" => run_puzzle(input, y2024::day25::part1, y2024::day25::part2),
-        // 2025
-        "2025-12-01" => run_puzzle(input, y2025::day01::part1, y2025::day01::part2),
-        "2025-12-02" => run_puzzle(input, y2025::day02::part1, y2025::day02::part2),
-        "2025-12-03" => run_puzzle(input, y2025::day03::part1, y2025::day03::part2),
-        _ => {
-            println!("Unrecognized PUZZLE '{puzzle}'");
-            std::process::exit(1);
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "y2024")]
+    fn test_solve() {
+        let day01: PuzzleId = "2024-12-01".parse().unwrap();
+        let day17: PuzzleId = "2024-12-17".parse().unwrap();
+        let unknown: PuzzleId = "2015-12-01".parse().unwrap();
+
+        let input = "3   4\r\n4   3\r\n2   5\r\n1   3\r\n3   9\r\n3   3";
+        assert_eq!("31", solve(day01, Part::Two, input).unwrap().as_str());
+
+        assert_eq!(
+            Err(AocError::UnknownPuzzle(unknown)),
+            solve(unknown, Part::One, input)
+        );
+
+        let err = solve(day17, Part::One, input).unwrap_err();
+        assert!(
+            matches!(err, AocError::SolutionPanicked { id, part: Part::One, .. } if id == day17)
+        );
+    }
+
+    #[test]
+    fn test_puzzles() {
+        assert!(puzzles().all(|p| registry::find(p.id).is_some()));
+        assert!(puzzles().is_sorted_by_key(|p| p.id));
     }
 }
@@ -0,0 +1,284 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Span based profiling of solution phases.
+//!
+//! A solution marks a phase with a guard from [`span`], and the time until the guard is
+//! dropped is added to that span. Spans opened while another is open are nested under it,
+//! so the same function called from different places is recorded separately for each.
+//!
+//! Spans are recorded per thread, and only while profiling is enabled with [`set_enabled`].
+//! Otherwise a span does nothing.
+
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static RECORDER: RefCell<Profile> = RefCell::new(Profile::default());
+    static STACK: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Starts timing `name` under the innermost open span, until the guard is dropped.
+#[must_use = "the span ends when the guard is dropped"]
+pub fn span(name: &'static str) -> Span {
+    if !enabled() {
+        return Span { started: None };
+    }
+
+    let node = RECORDER.with_borrow_mut(|profile| {
+        let parent = STACK.with_borrow(|stack| stack.last().copied());
+        profile.child(parent, name)
+    });
+    STACK.with_borrow_mut(|stack| stack.push(node));
+
+    Span {
+        started: Some((node, Instant::now())),
+    }
+}
+
+/// Takes the spans recorded on this thread so far, leaving an empty profile in their place.
+///
+/// Spans that are still open are recorded into the new profile when they end.
+#[must_use]
+pub fn take() -> Profile {
+    let profile = RECORDER.take();
+    STACK.with_borrow_mut(Vec::clear);
+    profile
+}
+
+/// A guard timing a span until it's dropped.
+pub struct Span {
+    started: Option<(usize, Instant)>,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some((node, started)) = self.started else {
+            return;
+        };
+
+        let elapsed = started.elapsed();
+        STACK.with_borrow_mut(|stack| {
+            if stack.last() == Some(&node) {
+                stack.pop();
+            }
+        });
+        RECORDER.with_borrow_mut(|profile| {
+            // the profile may have been taken while this span was open
+            if let Some(n) = profile.nodes.get_mut(node) {
+                n.count += 1;
+                n.total += elapsed;
+            }
+        });
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    name: &'static str,
+    parent: Option<usize>,
+    count: u64,
+    total: Duration,
+    children: Vec<usize>,
+}
+
+/// The spans recorded on a thread, as a tree of their names.
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    nodes: Vec<Node>,
+    roots: Vec<usize>,
+}
+
+impl Profile {
+    /// Finds the span called `name` under `parent`, adding it if it's new.
+    fn child(&mut self, parent: Option<usize>, name: &'static str) -> usize {
+        let siblings = match parent {
+            Some(p) => &self.nodes[p].children,
+            None => &self.roots,
+        };
+        if let Some(&existing) = siblings.iter().find(|&&n| self.nodes[n].name == name) {
+            return existing;
+        }
+
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            name,
+            parent,
+            count: 0,
+            total: Duration::ZERO,
+            children: vec![],
+        });
+        match parent {
+            Some(p) => self.nodes[p].children.push(node),
+            None => self.roots.push(node),
+        }
+
+        node
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The time spent in a span outside of any of its nested spans.
+    fn self_time(&self, node: usize) -> Duration {
+        let n = &self.nodes[node];
+        let nested: Duration = n.children.iter().map(|&c| self.nodes[c].total).sum();
+        n.total.saturating_sub(nested)
+    }
+
+    /// The names of the spans from the outermost one down to `node`.
+    fn path(&self, node: usize) -> Vec<&'static str> {
+        let mut path = vec![];
+        let mut current = Some(node);
+        while let Some(n) = current {
+            path.push(self.nodes[n].name);
+            current = self.nodes[n].parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Each span and its nested spans in the order they were first entered.
+    fn preorder(&self) -> Vec<(usize, usize)> {
+        let mut order = vec![];
+        let mut stack: Vec<(usize, usize)> = self.roots.iter().rev().map(|&n| (n, 0)).collect();
+        while let Some((node, depth)) = stack.pop() {
+            order.push((node, depth));
+            stack.extend(
+                self.nodes[node]
+                    .children
+                    .iter()
+                    .rev()
+                    .map(|&c| (c, depth + 1)),
+            );
+        }
+        order
+    }
+
+    /// A table of every span indented under its parent, with its total time, the number of
+    /// times it was entered, and its share of its parent's time.
+    #[must_use]
+    pub fn report(&self) -> String {
+        let order = self.preorder();
+        let labels: Vec<String> = order
+            .iter()
+            .map(|&(node, depth)| format!("{}{}", "  ".repeat(depth), self.nodes[node].name))
+            .collect();
+        let width = labels.iter().map(String::len).max().unwrap_or(0).max(4);
+
+        let mut s = format!(
+            "{:<width$}  {:>12}  {:>7}  {:>6}\n",
+            "SPAN", "TIME", "CALLS", "%"
+        );
+        for ((node, _), label) in order.into_iter().zip(labels) {
+            let n = &self.nodes[node];
+            let share = match n.parent {
+                Some(p) if !self.nodes[p].total.is_zero() => {
+                    format!(
+                        "{:.1}",
+                        100.0 * n.total.as_secs_f64() / self.nodes[p].total.as_secs_f64()
+                    )
+                }
+                _ => String::new(),
+            };
+            let total = format!("{:.2?}", n.total);
+            writeln!(
+                s,
+                "{label:<width$}  {total:>12}  {:>7}  {share:>6}",
+                n.count
+            )
+            .unwrap();
+        }
+
+        s
+    }
+
+    /// The profile in the folded stack format read by flamegraph tools: one line per span
+    /// with the `;` separated names leading to it and its self time in microseconds.
+    ///
+    /// Every stack is prefixed with `root`, if there is one.
+    #[must_use]
+    pub fn folded(&self, root: Option<&str>) -> String {
+        let mut s = String::new();
+        for (node, _) in self.preorder() {
+            let path = root.into_iter().chain(self.path(node)).collect::<Vec<_>>();
+            let micros = self.self_time(node).as_micros();
+            writeln!(s, "{} {micros}", path.join(";")).unwrap();
+        }
+
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span() {
+        set_enabled(true);
+        {
+            let _part = span("part 1");
+            for _ in 0..3 {
+                let _search = span("search");
+                let _inner = span("neighbors");
+            }
+            let _parse = span("parse");
+        }
+        let profile = take();
+        set_enabled(false);
+
+        let folded = profile.folded(Some("2024-12-16"));
+        let stacks: Vec<&str> = folded
+            .lines()
+            .map(|l| l.rsplit_once(' ').unwrap().0)
+            .collect();
+        let expected = vec![
+            "2024-12-16;part 1",
+            "2024-12-16;part 1;search",
+            "2024-12-16;part 1;search;neighbors",
+            "2024-12-16;part 1;parse",
+        ];
+        assert_eq!(stacks, expected);
+
+        let calls: Vec<u64> = profile
+            .preorder()
+            .iter()
+            .map(|&(n, _)| profile.nodes[n].count)
+            .collect();
+        assert_eq!(calls, vec![1, 3, 3, 1]);
+        assert!(profile.report().contains("\n    neighbors"));
+
+        assert!(take().is_empty());
+    }
+}
@@ -0,0 +1,184 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Progress reporting for long running solutions.
+//!
+//! A solution calls [`start`] before a long loop and updates the returned [`Progress`] as it
+//! goes. Updates are passed to the sink set with [`set_sink`] on the same thread, at most once
+//! per the interval it was set with, and to nothing if that thread has no sink. Keeping sinks
+//! per thread means solutions running at the same time, such as the requests of `serve`, never
+//! see each other's progress.
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// The least time between two updates the CLI's progress line shows.
+pub const UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How far along a solution is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Update {
+    /// What is being counted, such as `seconds simulated`.
+    pub label: &'static str,
+    pub done: u64,
+    /// How many there are in total, if it's known up front.
+    pub total: Option<u64>,
+    /// Whether this is the last update, sent when the solution finishes the loop.
+    pub finished: bool,
+}
+
+impl Update {
+    /// The fraction of the total that is done, if the total is known.
+    #[must_use]
+    pub fn fraction(&self) -> Option<f64> {
+        self.total
+            .filter(|&t| t > 0)
+            .map(|t| self.done as f64 / t as f64)
+    }
+}
+
+struct Sink {
+    send: Box<dyn Fn(&Update)>,
+    interval: Duration,
+}
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Sends the following updates on this thread to `sink`, leaving at least `interval` between
+/// two of them apart from the final one.
+pub fn set_sink(interval: Duration, sink: impl Fn(&Update) + 'static) {
+    SINK.set(Some(Sink {
+        send: Box::new(sink),
+        interval,
+    }));
+}
+
+/// Stops sending the following updates on this thread anywhere.
+pub fn clear_sink() {
+    SINK.set(None);
+}
+
+/// Starts reporting progress counting `label`, out of `total` if it's known.
+#[must_use]
+pub fn start(label: &'static str, total: Option<u64>) -> Progress {
+    Progress {
+        update: Update {
+            label,
+            done: 0,
+            total,
+            finished: false,
+        },
+        last_sent: None,
+        interval: SINK.with_borrow(|sink| sink.as_ref().map(|s| s.interval)),
+    }
+}
+
+/// A solution's progress through one loop. The final update is sent when it's dropped.
+pub struct Progress {
+    update: Update,
+    last_sent: Option<Instant>,
+    /// The sink's interval between updates, or `None` if there was no sink to send them to.
+    interval: Option<Duration>,
+}
+
+impl Progress {
+    pub fn inc(&mut self) {
+        self.set(self.update.done + 1);
+    }
+
+    pub fn set(&mut self, done: u64) {
+        self.update.done = done;
+        let Some(interval) = self.interval else {
+            return;
+        };
+
+        let now = Instant::now();
+        if self
+            .last_sent
+            .is_none_or(|sent| now.duration_since(sent) >= interval)
+        {
+            self.last_sent = Some(now);
+            send(&self.update);
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.interval.is_some() {
+            self.update.finished = true;
+            send(&self.update);
+        }
+    }
+}
+
+fn send(update: &Update) {
+    // this runs while the thread is exiting too, after its sink may already be gone
+    let _ = SINK.try_with(|sink| {
+        if let Some(sink) = sink.borrow().as_ref() {
+            (sink.send)(update);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    fn count_steps(interval: Duration) -> Vec<(u64, bool)> {
+        let updates = Rc::new(RefCell::new(vec![]));
+        let sink_updates = Rc::clone(&updates);
+        set_sink(interval, move |u| {
+            sink_updates.borrow_mut().push((u.done, u.finished));
+        });
+
+        let mut progress = start("steps", Some(4));
+        for _ in 0..3 {
+            progress.inc();
+        }
+        drop(progress);
+        clear_sink();
+
+        updates.take()
+    }
+
+    #[test]
+    fn test_progress() {
+        assert_eq!(
+            count_steps(Duration::ZERO),
+            vec![(1, false), (2, false), (3, false), (3, true)]
+        );
+        // the updates in between come too soon after the first to be sent
+        assert_eq!(count_steps(Duration::MAX), vec![(1, false), (3, true)]);
+
+        let mut progress = start("steps", None);
+        progress.inc();
+        assert_eq!(progress.interval, None);
+
+        let update = Update {
+            label: "steps",
+            done: 3,
+            total: Some(4),
+            finished: true,
+        };
+        assert_eq!(update.fraction(), Some(0.75));
+    }
+}
@@ -0,0 +1,206 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Python bindings for exploring the solutions and shared helpers from notebooks.
+//!
+//! Graph helpers take adjacency as a Python `dict` and accept any hashable value as a node.
+
+use crate::y2024::graph::{self, AdjacencyList};
+use crate::y2024::maze::Maze;
+use crate::{AocError, Part, PuzzleId};
+use pyo3::exceptions::{PyLookupError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Assigns each Python node an index so the Rust helpers can work with `usize` vertices.
+struct Nodes<'py> {
+    index: Bound<'py, PyDict>,
+    nodes: Vec<Bound<'py, PyAny>>,
+}
+
+impl<'py> Nodes<'py> {
+    fn new(py: Python<'py>) -> Nodes<'py> {
+        Nodes {
+            index: PyDict::new(py),
+            nodes: vec![],
+        }
+    }
+
+    fn get(&self, node: &Bound<'py, PyAny>) -> PyResult<Option<usize>> {
+        self.index
+            .get_item(node)?
+            .map(|i| i.extract::<usize>())
+            .transpose()
+    }
+
+    fn insert(&mut self, node: Bound<'py, PyAny>) -> PyResult<usize> {
+        if let Some(i) = self.get(&node)? {
+            return Ok(i);
+        }
+
+        let i = self.nodes.len();
+        self.index.set_item(&node, i)?;
+        self.nodes.push(node);
+
+        Ok(i)
+    }
+
+    fn node(&self, i: usize) -> Bound<'py, PyAny> {
+        self.nodes[i].clone()
+    }
+}
+
+/// A weighted directed graph given by Python, searched with the shared `Maze` algorithms.
+struct IndexedMaze {
+    edges: Vec<Vec<(u32, usize)>>,
+}
+
+impl Maze for IndexedMaze {
+    type Node = usize;
+
+    fn neighbors(&self, n: &usize) -> Vec<(u32, usize)> {
+        self.edges.get(*n).cloned().unwrap_or_default()
+    }
+}
+
+/// Reads `{node: [(weight, neighbor), ...]}` into an `IndexedMaze`.
+fn indexed_maze<'py>(adjacency: &Bound<'py, PyDict>) -> PyResult<(Nodes<'py>, IndexedMaze)> {
+    let mut nodes = Nodes::new(adjacency.py());
+    let mut edges: Vec<Vec<(u32, usize)>> = vec![];
+
+    for (node, neighbors) in adjacency.iter() {
+        let node = nodes.insert(node)?;
+        for edge in neighbors.try_iter()? {
+            let (weight, neighbor): (u32, Bound<'py, PyAny>) = edge?.extract()?;
+            let neighbor = nodes.insert(neighbor)?;
+
+            edges.resize(nodes.nodes.len(), vec![]);
+            edges[node].push((weight, neighbor));
+        }
+    }
+
+    Ok((nodes, IndexedMaze { edges }))
+}
+
+/// Solves part 1 or 2 of a puzzle and returns the answer as a string.
+#[pyfunction]
+fn solve(year: u16, day: u8, part: u8, input: &str) -> PyResult<String> {
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(PyValueError::new_err("part must be 1 or 2")),
+    };
+
+    match crate::solve(PuzzleId { year, day }, part, input) {
+        Ok(answer) => Ok(answer.into_string()),
+        Err(e @ AocError::UnknownPuzzle(_)) => Err(PyLookupError::new_err(e.to_string())),
+        Err(e @ AocError::SolutionPanicked { .. }) => Err(PyRuntimeError::new_err(e.to_string())),
+    }
+}
+
+/// Returns the registered puzzles as YYYY-12-DD dates.
+#[pyfunction]
+fn puzzles() -> Vec<String> {
+    crate::puzzles().map(|p| p.id.to_string()).collect()
+}
+
+/// Returns the largest cliques of the undirected graph `{node: [neighbor, ...]}`.
+/// Like the Rust version, cliques of two or fewer nodes aren't reported.
+#[pyfunction]
+fn max_cliques<'py>(adjacency: &Bound<'py, PyDict>) -> PyResult<Vec<Vec<Bound<'py, PyAny>>>> {
+    let mut nodes = Nodes::new(adjacency.py());
+    let mut graph = AdjacencyList::new();
+
+    for (node, neighbors) in adjacency.iter() {
+        let node = nodes.insert(node)?;
+        graph.add_vertex(node);
+        for neighbor in neighbors.try_iter()? {
+            let neighbor = nodes.insert(neighbor?)?;
+            graph.add_edge(node, neighbor);
+        }
+    }
+
+    let cliques = graph::max_cliques(&graph)
+        .into_iter()
+        .map(|clique| clique.into_iter().map(|i| nodes.node(i)).collect())
+        .collect();
+
+    Ok(cliques)
+}
+
+/// Runs Dijkstra's algorithm over the directed graph `{node: [(weight, neighbor), ...]}`.
+/// Returns `(distances, predecessors)`, where predecessors lists every node that precedes a
+/// node on some shortest path.
+#[pyfunction]
+#[allow(clippy::type_complexity)]
+fn dijkstra<'py>(
+    adjacency: &Bound<'py, PyDict>,
+    source: Bound<'py, PyAny>,
+) -> PyResult<(Bound<'py, PyDict>, Bound<'py, PyDict>)> {
+    let py = adjacency.py();
+    let (mut nodes, maze) = indexed_maze(adjacency)?;
+    let source = nodes.insert(source)?;
+    let (dist, prev) = maze.dijkstra(&source);
+
+    let distances = PyDict::new(py);
+    for (node, d) in dist {
+        distances.set_item(nodes.node(node), d)?;
+    }
+
+    let predecessors = PyDict::new(py);
+    for (node, ps) in prev {
+        let ps: Vec<Bound<'py, PyAny>> = ps.into_iter().map(|p| nodes.node(p)).collect();
+        predecessors.set_item(nodes.node(node), ps)?;
+    }
+
+    Ok((distances, predecessors))
+}
+
+/// Returns every shortest path from `source` to `target` in the directed graph
+/// `{node: [(weight, neighbor), ...]}`.
+#[pyfunction]
+fn shortest_paths<'py>(
+    adjacency: &Bound<'py, PyDict>,
+    source: Bound<'py, PyAny>,
+    target: Bound<'py, PyAny>,
+) -> PyResult<Vec<Vec<Bound<'py, PyAny>>>> {
+    let (mut nodes, maze) = indexed_maze(adjacency)?;
+    let source = nodes.insert(source)?;
+    let target = nodes.insert(target)?;
+    let (dist, prev) = maze.dijkstra(&source);
+
+    let paths = maze
+        .shortest_paths(&dist, &prev, &source, &target)
+        .into_iter()
+        .map(|path| path.into_iter().map(|i| nodes.node(i)).collect())
+        .collect();
+
+    Ok(paths)
+}
+
+#[pymodule]
+#[pyo3(name = "aoc")]
+fn python_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(puzzles, m)?)?;
+    m.add_function(wrap_pyfunction!(max_cliques, m)?)?;
+    m.add_function(wrap_pyfunction!(dijkstra, m)?)?;
+    m.add_function(wrap_pyfunction!(shortest_paths, m)?)?;
+
+    Ok(())
+}
@@ -0,0 +1,516 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#[cfg(feature = "y2024")]
+use crate::y2024;
+#[cfg(feature = "y2025")]
+use crate::y2025;
+use std::fmt;
+use std::str::FromStr;
+
+/// Identifies a puzzle by its date, formatted as `YYYY-12-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: u16,
+    pub day: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePuzzleIdError(String);
+
+impl fmt::Display for ParsePuzzleIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' is not a puzzle date of the form YYYY-12-DD",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParsePuzzleIdError {}
+
+impl FromStr for PuzzleId {
+    type Err = ParsePuzzleIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParsePuzzleIdError(s.to_string());
+
+        let (year, rest) = s.split_once('-').ok_or_else(err)?;
+        let day = rest.strip_prefix("12-").ok_or_else(err)?;
+        if year.len() != 4 || day.len() != 2 {
+            return Err(err());
+        }
+
+        let year = year.parse().map_err(|_| err())?;
+        let day = day.parse().map_err(|_| err())?;
+        if !(1..=25).contains(&day) {
+            return Err(err());
+        }
+
+        Ok(PuzzleId { year, day })
+    }
+}
+
+impl fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-12-{:02}", self.year, self.day)
+    }
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    #[must_use]
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("'{s}' is not a puzzle part, expected 1 or 2")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Broad categories of puzzles, used to find solutions to borrow from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tag {
+    BruteForce,
+    Dp,
+    Graph,
+    Grid,
+    Math,
+    Parsing,
+    Pathfinding,
+    Search,
+    Simulation,
+    Sorting,
+    Vm,
+}
+
+impl Tag {
+    #[must_use]
+    pub fn all() -> Vec<Tag> {
+        vec![
+            Tag::BruteForce,
+            Tag::Dp,
+            Tag::Graph,
+            Tag::Grid,
+            Tag::Math,
+            Tag::Parsing,
+            Tag::Pathfinding,
+            Tag::Search,
+            Tag::Simulation,
+            Tag::Sorting,
+            Tag::Vm,
+        ]
+    }
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Tag::BruteForce => "brute-force",
+            Tag::Dp => "dp",
+            Tag::Graph => "graph",
+            Tag::Grid => "grid",
+            Tag::Math => "math",
+            Tag::Parsing => "parsing",
+            Tag::Pathfinding => "pathfinding",
+            Tag::Search => "search",
+            Tag::Simulation => "simulation",
+            Tag::Sorting => "sorting",
+            Tag::Vm => "vm",
+        }
+    }
+}
+
+impl FromStr for Tag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tag::all()
+            .into_iter()
+            .find(|t| t.name() == s)
+            .ok_or_else(|| format!("'{s}' is not a known tag"))
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Solves one part of a puzzle for an input.
+pub type Solution = fn(&str) -> String;
+
+/// The name of the implementation in a puzzle's `part1` and `part2`.
+pub const DEFAULT_IMPL: &str = "default";
+
+/// An alternative approach to one part of a puzzle, kept around to compare against the default.
+pub struct Implementation {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: Solution,
+}
+
+/// A registered solution along with a cheap check of whether an input belongs to it.
+pub struct Puzzle {
+    pub id: PuzzleId,
+    pub title: &'static str,
+    pub tags: &'static [Tag],
+    /// Shared modules the solution is built on, such as `maze` or `graph`.
+    pub modules: &'static [&'static str],
+    pub part1: Solution,
+    pub part2: Solution,
+    pub alternatives: &'static [Implementation],
+    pub looks_like_input: fn(&str) -> bool,
+}
+
+impl Puzzle {
+    pub fn solve(&self, part: Part, input: &str) -> String {
+        match part {
+            Part::One => (self.part1)(input),
+            Part::Two => (self.part2)(input),
+        }
+    }
+
+    /// The names and solutions of every implementation of `part`, the default one first.
+    #[must_use]
+    pub fn implementations(&self, part: Part) -> Vec<(&'static str, Solution)> {
+        let default = match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        };
+
+        std::iter::once((DEFAULT_IMPL, default))
+            .chain(
+                self.alternatives
+                    .iter()
+                    .filter(|i| i.part == part)
+                    .map(|i| (i.name, i.solve)),
+            )
+            .collect()
+    }
+
+    /// The implementation of `part` called `name`, if there is one.
+    #[must_use]
+    pub fn implementation(&self, part: Part, name: &str) -> Option<Solution> {
+        self.implementations(part)
+            .into_iter()
+            .find(|&(n, _)| n == name)
+            .map(|(_, solve)| solve)
+    }
+}
+
+#[cfg(any(feature = "y2024", feature = "y2025"))]
+macro_rules! puzzle {
+    (
+        $year:literal,
+        $day:literal,
+        $module:ident :: $day_module:ident,
+        $title:literal,
+        [$($tag:ident),*],
+        [$($shared:literal),*]
+        $(, [$(($impl_name:literal, $impl_part:ident, $impl_fn:ident)),*])?
+    ) => {
+        Puzzle {
+            id: PuzzleId {
+                year: $year,
+                day: $day,
+            },
+            title: $title,
+            tags: &[$(Tag::$tag),*],
+            modules: &[$($shared),*],
+            part1: |input| $module::$day_module::part1(input).to_string(),
+            part2: |input| $module::$day_module::part2(input).to_string(),
+            alternatives: &[$($(Implementation {
+                name: $impl_name,
+                part: Part::$impl_part,
+                solve: |input| $module::$day_module::$impl_fn(input).to_string(),
+            }),*)?],
+            looks_like_input: $module::$day_module::looks_like_input,
+        }
+    };
+}
+
+#[cfg(feature = "y2024")]
+static Y2024: &[Puzzle] = &[
+    puzzle!(2024, 1, y2024::day01, "Historian Hysteria", [Sorting], []),
+    puzzle!(2024, 2, y2024::day02, "Red-Nosed Reports", [BruteForce], []),
+    puzzle!(2024, 3, y2024::day03, "Mull It Over", [Parsing], []),
+    puzzle!(2024, 4, y2024::day04, "Ceres Search", [Grid, Search], []),
+    puzzle!(
+        2024,
+        5,
+        y2024::day05,
+        "Print Queue",
+        [Graph, Sorting],
+        ["graph"]
+    ),
+    puzzle!(
+        2024,
+        6,
+        y2024::day06,
+        "Guard Gallivant",
+        [Grid, Simulation, BruteForce],
+        []
+    ),
+    puzzle!(
+        2024,
+        7,
+        y2024::day07,
+        "Bridge Repair",
+        [Search, BruteForce],
+        []
+    ),
+    puzzle!(
+        2024,
+        8,
+        y2024::day08,
+        "Resonant Collinearity",
+        [Grid, Math],
+        []
+    ),
+    puzzle!(2024, 9, y2024::day09, "Disk Fragmenter", [Simulation], []),
+    puzzle!(
+        2024,
+        10,
+        y2024::day10,
+        "Hoof It",
+        [Grid, Search],
+        ["traversal"]
+    ),
+    puzzle!(2024, 11, y2024::day11, "Plutonian Pebbles", [Dp], []),
+    puzzle!(2024, 12, y2024::day12, "Garden Groups", [Grid, Search], []),
+    puzzle!(
+        2024,
+        13,
+        y2024::day13,
+        "Claw Contraption",
+        [Math, Parsing],
+        []
+    ),
+    puzzle!(
+        2024,
+        14,
+        y2024::day14,
+        "Restroom Redoubt",
+        [Simulation, BruteForce],
+        []
+    ),
+    puzzle!(
+        2024,
+        15,
+        y2024::day15,
+        "Warehouse Woes",
+        [Grid, Simulation],
+        ["direction"]
+    ),
+    puzzle!(
+        2024,
+        16,
+        y2024::day16,
+        "Reindeer Maze",
+        [Grid, Pathfinding],
+        ["direction", "maze"]
+    ),
+    puzzle!(
+        2024,
+        17,
+        y2024::day17,
+        "Chronospatial Computer",
+        [Vm, Search],
+        []
+    ),
+    puzzle!(
+        2024,
+        18,
+        y2024::day18,
+        "RAM Run",
+        [Grid, Pathfinding],
+        ["graph", "maze", "traversal"],
+        [
+            ("dijkstra", One, part1_dijkstra),
+            ("dijkstra", Two, part2_dijkstra),
+            ("union-find", Two, part2_union_find)
+        ]
+    ),
+    puzzle!(2024, 19, y2024::day19, "Linen Layout", [Dp], []),
+    puzzle!(
+        2024,
+        20,
+        y2024::day20,
+        "Race Condition",
+        [Grid, Pathfinding],
+        ["traversal"]
+    ),
+    puzzle!(
+        2024,
+        21,
+        y2024::day21,
+        "Keypad Conundrum",
+        [Dp, Pathfinding],
+        []
+    ),
+    puzzle!(2024, 22, y2024::day22, "Monkey Market", [Simulation], []),
+    puzzle!(
+        2024,
+        23,
+        y2024::day23,
+        "LAN Party",
+        [Graph],
+        ["graph"],
+        [
+            ("loops", One, part1_loops),
+            ("hash-set", Two, part2_hash_set)
+        ]
+    ),
+    puzzle!(
+        2024,
+        24,
+        y2024::day24,
+        "Crossed Wires",
+        [Graph, Search],
+        ["graph"]
+    ),
+    puzzle!(2024, 25, y2024::day25, "Code Chronicle", [Parsing], []),
+];
+
+#[cfg(feature = "y2025")]
+static Y2025: &[Puzzle] = &[
+    puzzle!(2025, 1, y2025::day01, "Secret Entrance", [Math], []),
+    puzzle!(2025, 2, y2025::day02, "Gift Shop", [Math], []),
+    puzzle!(2025, 3, y2025::day03, "Lobby", [Search], []),
+];
+
+/// The registered puzzles of each year compiled in, see the per-year cargo features.
+static YEARS: &[&[Puzzle]] = &[
+    #[cfg(feature = "y2024")]
+    Y2024,
+    #[cfg(feature = "y2025")]
+    Y2025,
+];
+
+/// Every registered puzzle, in order of date.
+pub fn all() -> impl Iterator<Item = &'static Puzzle> {
+    YEARS.iter().flat_map(|year| year.iter())
+}
+
+#[must_use]
+pub fn find(id: PuzzleId) -> Option<&'static Puzzle> {
+    all().find(|p| p.id == id)
+}
+
+/// Every registered puzzle for `year`, in order of day.
+pub fn year(year: u16) -> impl Iterator<Item = &'static Puzzle> {
+    all().filter(move |p| p.id.year == year)
+}
+
+/// Guesses which registered puzzles an input could belong to.
+#[must_use]
+pub fn identify(input: &str) -> Vec<PuzzleId> {
+    all()
+        .filter(|p| (p.looks_like_input)(input))
+        .map(|p| p.id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_id() {
+        let tests = vec![
+            ("2024-12-01", Some(PuzzleId { year: 2024, day: 1 })),
+            (
+                "2025-12-25",
+                Some(PuzzleId {
+                    year: 2025,
+                    day: 25,
+                }),
+            ),
+            ("2024-12-1", None),
+            ("2024-11-01", None),
+            ("2024-12-26", None),
+            ("24-12-01", None),
+            ("2024", None),
+        ];
+
+        for (s, expected) in tests {
+            let id = s.parse::<PuzzleId>().ok();
+            assert_eq!(expected, id, "{s}");
+            if let Some(id) = id {
+                assert_eq!(s, id.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_tag() {
+        for tag in Tag::all() {
+            assert_eq!(Ok(tag), tag.to_string().parse());
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "y2024", feature = "y2025"))]
+    fn test_identify() {
+        let tests = vec![
+            ("3   4\n4   3\n2   5\n", "2024-12-01"),
+            ("7 6 4 2 1\n1 2 7 8 9\n", "2024-12-02"),
+            (
+                "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n",
+                "2024-12-13",
+            ),
+            (
+                "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n",
+                "2024-12-17",
+            ),
+            ("kh-tc\nqp-kh\nde-cg\n", "2024-12-23"),
+            ("L68\nL30\nR48\n", "2025-12-01"),
+        ];
+
+        for (input, expected) in tests {
+            let expected: PuzzleId = expected.parse().unwrap();
+            assert_eq!(vec![expected], identify(input), "{input:?}");
+        }
+    }
+}
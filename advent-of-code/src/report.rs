@@ -0,0 +1,171 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::answers::Answers;
+use crate::cache::AnswerCache;
+use crate::registry::{self, Part, Puzzle};
+use std::fmt::Write;
+use std::time::Duration;
+
+/// One day of the calendar as it appears in a report.
+struct Row {
+    puzzle: &'static Puzzle,
+    stars: usize,
+    timings: [Option<Duration>; 2],
+}
+
+fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = registry::all().map(|p| p.id.year).collect();
+    years.dedup();
+    years
+}
+
+fn rows(year: u16, answers: &Answers, cache: &AnswerCache) -> Vec<Row> {
+    registry::year(year)
+        .map(|puzzle| Row {
+            puzzle,
+            stars: answers.count(puzzle.id),
+            timings: Part::all().map(|part| cache.latest(puzzle.id, part).map(|c| c.elapsed)),
+        })
+        .collect()
+}
+
+fn puzzle_url(puzzle: &Puzzle) -> String {
+    format!(
+        "https://adventofcode.com/{}/day/{}",
+        puzzle.id.year, puzzle.id.day
+    )
+}
+
+fn stars(earned: usize) -> String {
+    "★".repeat(earned) + &"☆".repeat(2 - earned)
+}
+
+fn timing(elapsed: Option<Duration>) -> String {
+    elapsed.map_or_else(|| "-".to_string(), |d| format!("{d:.2?}"))
+}
+
+/// A Markdown calendar of every registered puzzle, suitable for pasting into the README.
+///
+/// Stars come from the recorded answers and timings from the latest cached answers.
+#[must_use]
+pub fn markdown(answers: &Answers, cache: &AnswerCache) -> String {
+    let mut s = String::from("# Advent of Code\n");
+
+    for year in years() {
+        let rows = rows(year, answers, cache);
+        let total: usize = rows.iter().map(|r| r.stars).sum();
+
+        let _ = writeln!(s, "\n## {year}\n\n{total} stars\n");
+        s.push_str("| Day | Puzzle | Stars | Part 1 | Part 2 |\n");
+        s.push_str("| --: | ------ | ----- | -----: | -----: |\n");
+        for row in rows {
+            let _ = writeln!(
+                s,
+                "| {} | [{}]({}) | {} | {} | {} |",
+                row.puzzle.id.day,
+                row.puzzle.title,
+                puzzle_url(row.puzzle),
+                stars(row.stars),
+                timing(row.timings[0]),
+                timing(row.timings[1]),
+            );
+        }
+    }
+
+    s
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The same calendar as [`markdown`], as a standalone HTML page.
+#[must_use]
+pub fn html(answers: &Answers, cache: &AnswerCache) -> String {
+    let mut s = String::from(
+        "\
+<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>Advent of Code</title>
+<style>
+body { font-family: monospace; background: #0f0f23; color: #cccccc; }
+a { color: #009900; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 1em; text-align: left; }
+.stars { color: #ffff66; }
+</style>
+</head>
+<body>
+<h1>Advent of Code</h1>
+",
+    );
+
+    for year in years() {
+        let rows = rows(year, answers, cache);
+        let total: usize = rows.iter().map(|r| r.stars).sum();
+
+        let _ = writeln!(s, "<h2>{year}</h2>\n<p>{total} stars</p>");
+        s.push_str("<table>\n");
+        s.push_str(
+            "<tr><th>Day</th><th>Puzzle</th><th>Stars</th><th>Part 1</th><th>Part 2</th></tr>\n",
+        );
+        for row in rows {
+            let _ = writeln!(
+                s,
+                "<tr><td>{}</td><td><a href=\"{}\">{}</a></td><td class=\"stars\">{}</td><td>{}</td><td>{}</td></tr>",
+                row.puzzle.id.day,
+                puzzle_url(row.puzzle),
+                escape_html(row.puzzle.title),
+                stars(row.stars),
+                escape_html(&timing(row.timings[0])),
+                escape_html(&timing(row.timings[1])),
+            );
+        }
+        s.push_str("</table>\n");
+    }
+
+    s.push_str("</body>\n</html>\n");
+    s
+}
+
+#[cfg(all(test, feature = "y2024", feature = "y2025"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown() {
+        let answers = Answers::parse("2024-12-01 1 11\n2024-12-01 2 31\n2024-12-02 1 2\n");
+        let report = markdown(&answers, &AnswerCache::default());
+
+        assert!(report.contains("## 2024\n\n3 stars\n"));
+        assert!(report.contains(
+            "| 1 | [Historian Hysteria](https://adventofcode.com/2024/day/1) | ★★ | - | - |"
+        ));
+        assert!(
+            report
+                .contains("| 2 | [Red-Nosed Reports](https://adventofcode.com/2024/day/2) | ★☆ |")
+        );
+        assert!(report.contains("## 2025\n\n0 stars\n"));
+    }
+}
@@ -0,0 +1,224 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::{AocError, Part, PuzzleId};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Instant;
+
+/// Puzzle inputs are a few tens of kilobytes, anything much larger is a mistake.
+const MAX_BODY: usize = 10 * 1024 * 1024;
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn ok(body: String) -> Response {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response {
+            status,
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if u32::from(c) < 0x20 => escaped.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+
+    escaped
+}
+
+/// Serves solutions over HTTP until the listener fails, handling each connection on its own
+/// thread.
+///
+/// * `GET /puzzles` lists the registered puzzles.
+/// * `POST /solve/{year}/{day}/{part}` solves the puzzle using the request body as input.
+///
+/// # Errors
+/// Returns an error if accepting a connection fails.
+pub fn serve(listener: &TcpListener) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        std::thread::spawn(move || {
+            if let Err(e) = handle(stream) {
+                eprintln!("Failed to handle request: {e}");
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream) -> io::Result<()> {
+    let response = match read_request(&stream) {
+        Ok(request) => route(&request),
+        Err(response) => response,
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+fn read_request(stream: &TcpStream) -> Result<Request, Response> {
+    let bad_request = |message: &str| Response::error(400, message);
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .map_err(|_| bad_request("failed to read request"))?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(bad_request("malformed request line"));
+    };
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader
+            .read_line(&mut header)
+            .map_err(|_| bad_request("failed to read headers"))?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value
+                .trim()
+                .parse()
+                .map_err(|_| bad_request("invalid Content-Length"))?;
+        }
+    }
+
+    if content_length > MAX_BODY {
+        return Err(Response::error(413, "input is too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| bad_request("failed to read body"))?;
+    let body = String::from_utf8(body).map_err(|_| bad_request("input is not UTF-8"))?;
+
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        body,
+    })
+}
+
+fn route(request: &Request) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["puzzles"]) => Response::ok(puzzles()),
+        ("POST", ["solve", year, day, part]) => solve(year, day, part, &request.body),
+        (_, ["puzzles"] | ["solve", _, _, _]) => Response::error(405, "method not allowed"),
+        _ => Response::error(404, "not found"),
+    }
+}
+
+fn puzzles() -> String {
+    let puzzles: Vec<String> = crate::puzzles()
+        .map(|p| {
+            let tags: Vec<String> = p.tags.iter().map(|t| json_string(t.name())).collect();
+            format!(
+                "{{\"id\":{},\"year\":{},\"day\":{},\"title\":{},\"tags\":[{}]}}",
+                json_string(&p.id.to_string()),
+                p.id.year,
+                p.id.day,
+                json_string(p.title),
+                tags.join(",")
+            )
+        })
+        .collect();
+
+    format!("[{}]", puzzles.join(","))
+}
+
+fn solve(year: &str, day: &str, part: &str, input: &str) -> Response {
+    let (Ok(year), Ok(day)) = (year.parse(), day.parse()) else {
+        return Response::error(400, "year and day must be numbers");
+    };
+    let id = PuzzleId { year, day };
+
+    let Ok(part) = part.parse::<Part>() else {
+        return Response::error(400, "part must be 1 or 2");
+    };
+
+    let start = Instant::now();
+    let result = crate::solve(id, part, input);
+    let elapsed = start.elapsed();
+
+    match result {
+        Ok(answer) => Response::ok(format!(
+            "{{\"id\":{},\"part\":{part},\"answer\":{},\"elapsed_ms\":{}}}",
+            json_string(&id.to_string()),
+            json_string(answer.as_str()),
+            elapsed.as_secs_f64() * 1000.0
+        )),
+        Err(e @ AocError::UnknownPuzzle(_)) => Response::error(404, &e.to_string()),
+        Err(e @ AocError::SolutionPanicked { .. }) => Response::error(422, &e.to_string()),
+    }
+}
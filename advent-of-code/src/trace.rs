@@ -0,0 +1,189 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Leveled tracing of what solutions are doing.
+//!
+//! Solutions emit events with the crate's `info!` and `debug!` macros, which only evaluate their
+//! arguments when that level is enabled, so expensive renderings cost nothing otherwise. An event
+//! can carry named fields ahead of its message, as in `info!(seconds = t; "found it after {t}")`.
+//! Events are passed to the sink set with [`set_sink`], or printed to stderr if there isn't one.
+
+use std::fmt;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// A summary of what a solution found, such as the answer it settled on.
+    Info = 1,
+    /// Intermediate state, such as a rendering of a grid or disk.
+    Debug = 2,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+        }
+    }
+}
+
+/// Something a solution reported while running.
+pub struct Event<'a> {
+    pub level: Level,
+    /// The module the event was emitted from, such as `aoc::y2024::day16`.
+    pub target: &'static str,
+    /// Named values behind the message, so a sink can filter or render them on their own.
+    pub fields: &'a [(&'static str, &'a dyn fmt::Display)],
+    pub message: fmt::Arguments<'a>,
+}
+
+impl fmt::Display for Event<'_> {
+    /// Writes the message followed by each field as `name=value`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        for (name, value) in self.fields {
+            write!(f, " {name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+type Sink = Box<dyn Fn(&Event) + Send + Sync>;
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+static SINK: RwLock<Option<Sink>> = RwLock::new(None);
+
+/// Enables events up to and including `level`, or disables tracing for `None`.
+pub fn set_level(level: Option<Level>) {
+    LEVEL.store(level.map_or(0, |l| l as u8), Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Sends every following event to `sink` instead of stderr.
+///
+/// # Panics
+/// Panics if a previous sink panicked while handling an event.
+pub fn set_sink(sink: impl Fn(&Event) + Send + Sync + 'static) {
+    *SINK.write().unwrap() = Some(Box::new(sink));
+}
+
+/// Passes an event to the sink. Use the crate's `info!` and `debug!` macros instead, which check
+/// that the level is enabled first.
+///
+/// # Panics
+/// Panics if a previous sink panicked while handling an event.
+pub fn emit(
+    level: Level,
+    target: &'static str,
+    fields: &[(&'static str, &dyn fmt::Display)],
+    message: fmt::Arguments,
+) {
+    let event = Event {
+        level,
+        target,
+        fields,
+        message,
+    };
+
+    match SINK.read().unwrap().as_ref() {
+        Some(sink) => sink(&event),
+        None => eprintln!("[{level}] {target}: {event}"),
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! event {
+    ($level:ident, $($name:ident = $value:expr),+ ; $($arg:tt)+) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::emit(
+                $crate::trace::Level::$level,
+                module_path!(),
+                &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+                format_args!($($arg)+),
+            );
+        }
+    };
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::emit($crate::trace::Level::$level, module_path!(), &[], format_args!($($arg)+));
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::trace::event!(Info, $($arg)+)
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::trace::event!(Debug, $($arg)+)
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use {debug, event, info};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_levels() {
+        let events = Arc::new(Mutex::new(vec![]));
+        let sink_events = Arc::clone(&events);
+        set_sink(move |e| {
+            // other tests may emit events while this one runs
+            if e.target == module_path!() {
+                sink_events.lock().unwrap().push(format!("{} {e}", e.level));
+            }
+        });
+
+        let mut evaluated = 0;
+        let mut render = || {
+            evaluated += 1;
+            "grid"
+        };
+
+        set_level(Some(Level::Info));
+        info!(answer = 42, tries = 3; "found {}", 42);
+        debug!("{}", render());
+
+        set_level(Some(Level::Debug));
+        debug!("{}", render());
+
+        set_level(None);
+        info!("found {}", 43);
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec!["info found 42 answer=42 tries=3", "debug grid"]
+        );
+        assert_eq!(evaluated, 1);
+    }
+}
@@ -22,6 +22,15 @@ fn parse_pair(line: &str) -> (u32, u32) {
     (left.parse().unwrap(), right.parse().unwrap())
 }
 
+/// Checks that the input looks like two columns of location IDs.
+pub fn looks_like_input(input: &str) -> bool {
+    !input.trim().is_empty()
+        && input.lines().take(10).all(|line| {
+            line.split_once("   ")
+                .is_some_and(|(l, r)| l.parse::<u32>().is_ok() && r.parse::<u32>().is_ok())
+        })
+}
+
 pub fn part1(input: &str) -> u32 {
     let (mut left, mut right): (Vec<u32>, Vec<u32>) = input.lines().map(parse_pair).unzip();
     left.sort_unstable();
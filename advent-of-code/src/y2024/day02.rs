@@ -55,6 +55,14 @@ fn safe_sequence_part2(seq: &[i32]) -> bool {
         .any(|idx| safe_sequence_dampen(seq, idx))
 }
 
+/// Checks that the input looks like several reports of space separated levels.
+pub fn looks_like_input(input: &str) -> bool {
+    input.lines().nth(1).is_some()
+        && input.lines().take(10).all(|line| {
+            line.split(' ').count() > 1 && line.split(' ').all(|n| n.parse::<i32>().is_ok())
+        })
+}
+
 pub fn part1(input: &str) -> usize {
     input
         .lines()
@@ -82,6 +82,11 @@ fn instruction_mul(input: &[u8], cursor: usize) -> Option<(usize, u32, u32)> {
     Some((cursor, num1, num2))
 }
 
+/// Checks that the input looks like corrupted memory with at least one `mul` instruction.
+pub fn looks_like_input(input: &str) -> bool {
+    input.contains("mul(")
+}
+
 pub fn part1(input: &str) -> u32 {
     let input = input.as_bytes();
     let mut cursor: usize = 0;
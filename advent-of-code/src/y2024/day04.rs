@@ -16,6 +16,14 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+/// Checks that the input looks like a word search made only of the letters in XMAS.
+pub fn looks_like_input(input: &str) -> bool {
+    !input.trim().is_empty()
+        && input
+            .lines()
+            .all(|line| line.chars().all(|c| matches!(c, 'X' | 'M' | 'A' | 'S')))
+}
+
 pub fn part1(input: &str) -> usize {
     let input = input.lines().map(|l| l.as_bytes()).collect::<Vec<&[u8]>>();
     let grid_width = input[0].len();
@@ -16,19 +16,23 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-fn parse(input: &str) -> (Vec<Vec<bool>>, Vec<Vec<usize>>) {
+use crate::y2024::graph::{DirectedAdjacencyList, is_consistent, topological_sort};
+
+/// Parses the page ordering rules as a graph with an edge from each page to the pages that
+/// must come after it, and the updates as lists of pages.
+fn parse(input: &str) -> (DirectedAdjacencyList<usize>, Vec<Vec<usize>>) {
     let mut input = input.split("\n\n");
     let rules_input = input.next().unwrap();
     let pages = input.next().unwrap();
 
-    let mut rules: Vec<Vec<bool>> = vec![vec![false; 100]; 100];
+    let mut rules = DirectedAdjacencyList::new();
 
     for line in rules_input.lines() {
         let mut nums = line.split("|").map(|x| x.parse().unwrap());
         let n1: usize = nums.next().unwrap();
         let n2: usize = nums.next().unwrap();
 
-        rules[n1][n2] = true;
+        rules.add_edge(n1, n2);
     }
 
     let pages: Vec<Vec<usize>> = pages
@@ -39,32 +43,79 @@ fn parse(input: &str) -> (Vec<Vec<bool>>, Vec<Vec<usize>>) {
     (rules, pages)
 }
 
+/// Checks that the input starts with a page ordering rule and has an update section.
+pub fn looks_like_input(input: &str) -> bool {
+    let first_rule = input.lines().next().and_then(|l| l.split_once('|'));
+
+    input.contains("\n\n")
+        && first_rule.is_some_and(|(a, b)| a.parse::<usize>().is_ok() && b.parse::<usize>().is_ok())
+}
+
 pub fn part1(input: &str) -> usize {
     let (rules, pages) = parse(input);
 
     pages
         .iter()
-        .filter(|nums| nums.is_sorted_by(|&a, &b| !rules[b][a]))
+        .filter(|nums| is_consistent(&rules, nums))
         .map(|nums| nums[nums.len() / 2])
         .sum()
 }
 
+/// # Panics
 pub fn part2(input: &str) -> usize {
-    let (rules, mut pages) = parse(input);
+    let (rules, pages) = parse(input);
 
     pages
-        .iter_mut()
-        .filter(|nums| !nums.is_sorted_by(|&a, &b| !rules[b][a]))
+        .iter()
+        .filter(|nums| !is_consistent(&rules, nums))
         .map(|nums| {
-            nums.sort_unstable_by(|&a, &b| {
-                if rules[b][a] {
-                    std::cmp::Ordering::Greater
-                } else {
-                    std::cmp::Ordering::Less
-                }
-            });
+            // the rules as a whole are cyclic, but the rules between the pages of an update aren't
+            let nums = topological_sort(&rules.induced_subgraph(nums))
+                .unwrap_or_else(|cycle| panic!("Rules for {nums:?} are cyclic between {cycle}"));
 
             nums[nums.len() / 2]
         })
         .sum()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE), 143);
+        assert_eq!(part2(EXAMPLE), 123);
+    }
+}
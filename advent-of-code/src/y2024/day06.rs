@@ -16,6 +16,7 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use crate::progress;
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -95,6 +96,14 @@ fn parse(input: &str) -> (Guard, Vec<Vec<bool>>) {
     (Guard(Direction::Up, x, y), maze)
 }
 
+/// Checks that the input looks like a lab map with a single guard facing up.
+pub fn looks_like_input(input: &str) -> bool {
+    input.chars().filter(|&c| c == '^').count() == 1
+        && input
+            .lines()
+            .all(|line| line.chars().all(|c| matches!(c, '.' | '#' | '^')))
+}
+
 pub fn part1(input: &str) -> usize {
     let (guard, maze) = parse(input);
     let positions = path(guard, &maze).expect("Guard is in a loop");
@@ -170,7 +179,9 @@ pub fn part2(input: &str) -> usize {
     }
 
     let mut count = 0;
+    let mut progress = progress::start("obstructions tried", Some(blocks.len() as u64));
     for pos in blocks.iter() {
+        progress.inc();
         let &(x, y) = pos;
         if let Some(&guard) = first_guard_positions.get(pos) {
             maze[y][x] = true;
@@ -183,3 +194,27 @@ pub fn part2(input: &str) -> usize {
 
     count
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(41, part1(EXAMPLE));
+        assert_eq!(6, part2(EXAMPLE));
+    }
+}
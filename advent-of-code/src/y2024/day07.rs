@@ -53,6 +53,16 @@ fn is_part1_calibration(numbers: &[i64], answer: i64, n: usize) -> bool {
     (answer - numbers[n] > 0) && is_part1_calibration(numbers, answer - numbers[n], n - 1)
 }
 
+/// Checks that the input looks like calibration equations of the form `TEST: N N ...`.
+pub fn looks_like_input(input: &str) -> bool {
+    !input.trim().is_empty()
+        && input.lines().take(10).all(|line| {
+            line.split_once(": ").is_some_and(|(ans, nums)| {
+                ans.parse::<i64>().is_ok() && nums.split(' ').all(|n| n.parse::<i64>().is_ok())
+            })
+        })
+}
+
 pub fn part1(input: &str) -> i64 {
     let equations = parse(input);
 
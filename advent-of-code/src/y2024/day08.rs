@@ -45,6 +45,15 @@ fn antinode(x1: usize, y1: usize, x2: usize, y2: usize, i: isize) -> (isize, isi
     (ax, ay)
 }
 
+/// Checks that the input looks like a map of antennas.
+pub fn looks_like_input(input: &str) -> bool {
+    input.contains('.')
+        && input.chars().any(|c| c.is_ascii_alphanumeric())
+        && input
+            .lines()
+            .all(|line| line.chars().all(|c| c == '.' || c.is_ascii_alphanumeric()))
+}
+
 pub fn part1(input: &str) -> usize {
     let (width, height, antenna) = parse(input);
     let width = isize::try_from(width).unwrap();
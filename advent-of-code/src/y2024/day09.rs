@@ -16,6 +16,8 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use crate::trace::debug;
+
 fn parse(input: &str) -> Vec<usize> {
     input
         .trim()
@@ -64,7 +66,6 @@ fn checksum(disk: &[Option<usize>]) -> usize {
         .sum()
 }
 
-#[allow(dead_code)]
 fn debug_disk(disk: &[Option<usize>]) -> String {
     let mut s = String::with_capacity(disk.len() * 2 + 1);
     for block in disk {
@@ -83,7 +84,6 @@ fn debug_disk(disk: &[Option<usize>]) -> String {
     s
 }
 
-#[allow(dead_code)]
 fn debug_diskmap(disk_map: &[usize]) -> String {
     let mut s = String::new();
 
@@ -94,8 +94,15 @@ fn debug_diskmap(disk_map: &[usize]) -> String {
     s
 }
 
+/// Checks that the input looks like a single line disk map of digits.
+pub fn looks_like_input(input: &str) -> bool {
+    let input = input.trim();
+    !input.is_empty() && input.chars().all(|c| c.is_ascii_digit())
+}
+
 pub fn part1(input: &str) -> usize {
     let disk_map = parse(input);
+    debug!("disk map {}", debug_diskmap(&disk_map));
 
     let mut disk = to_disk(&disk_map);
     let mut start = 0;
@@ -110,6 +117,8 @@ pub fn part1(input: &str) -> usize {
             end -= 1;
         }
     }
+
+    debug!("compacted disk {}", debug_disk(&disk));
     checksum(&disk)
 }
 
@@ -117,6 +126,7 @@ pub fn part2(input: &str) -> usize {
     let mut free_space_map = parse(input);
     let file_size_map = free_space_map.clone();
     let mut disk = to_disk(&free_space_map);
+    debug!("disk map {}", debug_diskmap(&free_space_map));
 
     let mut file_index = disk.len();
     for i in (0..free_space_map.len()).rev() {
@@ -149,5 +159,6 @@ pub fn part2(input: &str) -> usize {
         }
     }
 
+    debug!("compacted disk {}", debug_disk(&disk));
     checksum(&disk)
 }
@@ -16,7 +16,7 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::collections::HashSet;
+use crate::y2024::traversal::Bfs;
 
 fn parse(input: &str) -> Vec<Vec<usize>> {
     input
@@ -29,36 +29,35 @@ fn parse(input: &str) -> Vec<Vec<usize>> {
         .collect()
 }
 
-fn trailhead_score_part(grid: &Vec<Vec<usize>>, row: usize, col: usize) -> HashSet<(usize, usize)> {
-    if grid[row][col] == 9 {
-        let mut peaks = HashSet::new();
-        peaks.insert((row, col));
-        return peaks;
-    }
-
+/// The positions next to `(row, col)` that are one higher.
+fn uphill(grid: &[Vec<usize>], (row, col): (usize, usize)) -> Vec<(usize, usize)> {
     let elevation = grid[row][col];
-    let mut peaks = HashSet::new();
-    if row > 0 && elevation + 1 == grid[row - 1][col] {
-        peaks.extend(trailhead_score_part(grid, row - 1, col));
-    }
-
-    if row + 1 < grid[0].len() && elevation + 1 == grid[row + 1][col] {
-        peaks.extend(trailhead_score_part(grid, row + 1, col));
-    }
-
-    if col > 0 && elevation + 1 == grid[row][col - 1] {
-        peaks.extend(trailhead_score_part(grid, row, col - 1));
-    }
-
-    if col + 1 < grid[0].len() && elevation + 1 == grid[row][col + 1] {
-        peaks.extend(trailhead_score_part(grid, row, col + 1));
-    }
+    let adjacent = [
+        (row.wrapping_sub(1), col),
+        (row + 1, col),
+        (row, col.wrapping_sub(1)),
+        (row, col + 1),
+    ];
+
+    adjacent
+        .into_iter()
+        .filter(|&(r, c)| grid.get(r).and_then(|l| l.get(c)) == Some(&(elevation + 1)))
+        .collect()
+}
 
-    peaks
+fn trailhead_score(grid: &[Vec<usize>], start: (usize, usize)) -> usize {
+    Bfs::new(start, |&p| uphill(grid, p))
+        .filter(|v| grid[v.node.0][v.node.1] == 9)
+        .count()
 }
 
-fn trailhead_score(grid: &Vec<Vec<usize>>, start_row: usize, start_col: usize) -> usize {
-    trailhead_score_part(grid, start_row, start_col).len()
+/// Checks that the input looks like a topographic map containing trailheads.
+pub fn looks_like_input(input: &str) -> bool {
+    input.lines().nth(1).is_some()
+        && input.contains('0')
+        && input
+            .lines()
+            .all(|line| line.chars().all(|c| c.is_ascii_digit()))
 }
 
 pub fn part1(input: &str) -> usize {
@@ -70,7 +69,7 @@ pub fn part1(input: &str) -> usize {
     for row in 0..height {
         for col in 0..width {
             if grid[row][col] == 0 {
-                total += trailhead_score(&grid, row, col);
+                total += trailhead_score(&grid, (row, col));
             }
         }
     }
@@ -78,30 +77,16 @@ pub fn part1(input: &str) -> usize {
     total
 }
 
-fn trailhead_rating(grid: &Vec<Vec<usize>>, row: usize, col: usize) -> usize {
-    if grid[row][col] == 9 {
+fn trailhead_rating(grid: &[Vec<usize>], position: (usize, usize)) -> usize {
+    if grid[position.0][position.1] == 9 {
         return 1;
     }
 
-    let elevation = grid[row][col];
-    let mut score = 0;
-    if row > 0 && elevation + 1 == grid[row - 1][col] {
-        score += trailhead_rating(grid, row - 1, col);
-    }
-
-    if row + 1 < grid[0].len() && elevation + 1 == grid[row + 1][col] {
-        score += trailhead_rating(grid, row + 1, col);
-    }
-
-    if col > 0 && elevation + 1 == grid[row][col - 1] {
-        score += trailhead_rating(grid, row, col - 1);
-    }
-
-    if col + 1 < grid[0].len() && elevation + 1 == grid[row][col + 1] {
-        score += trailhead_rating(grid, row, col + 1);
-    }
-
-    score
+    // every trail is a different path, so unlike the score these can't be found with a traversal
+    uphill(grid, position)
+        .into_iter()
+        .map(|p| trailhead_rating(grid, p))
+        .sum()
 }
 
 pub fn part2(input: &str) -> usize {
@@ -113,10 +98,32 @@ pub fn part2(input: &str) -> usize {
     for row in 0..height {
         for col in 0..width {
             if grid[row][col] == 0 {
-                total += trailhead_rating(&grid, row, col);
+                total += trailhead_rating(&grid, (row, col));
             }
         }
     }
 
     total
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE), 36);
+        assert_eq!(part2(EXAMPLE), 81);
+    }
+}
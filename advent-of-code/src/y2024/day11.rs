@@ -53,6 +53,14 @@ fn num_stones_memo(cache: &mut HashMap<(u128, usize), usize>, stone: u128, blink
     ans
 }
 
+/// Checks that the input looks like a single line of engraved stones.
+pub fn looks_like_input(input: &str) -> bool {
+    let input = input.trim();
+    !input.is_empty()
+        && !input.contains('\n')
+        && input.split(' ').all(|n| n.parse::<u128>().is_ok())
+}
+
 pub fn part1(input: &str) -> usize {
     let stones = parse(input);
     let mut memo = HashMap::new();
@@ -178,6 +178,14 @@ where
     cost
 }
 
+/// Checks that the input looks like a garden plot map of plant letters.
+pub fn looks_like_input(input: &str) -> bool {
+    input.lines().nth(1).is_some()
+        && input
+            .lines()
+            .all(|line| line.chars().all(|c| c.is_ascii_uppercase()))
+}
+
 pub fn part1(input: &str) -> u32 {
     solution(input, |cells| {
         let perimeter: u32 = perimeter(cells);
@@ -82,6 +82,11 @@ fn machine_tokens((button_a, button_b, prize): Puzzle<i128>) -> Option<i128> {
     Some(ans)
 }
 
+/// Checks that the input starts with a claw machine description.
+pub fn looks_like_input(input: &str) -> bool {
+    input.starts_with("Button A: ")
+}
+
 pub fn part1(input: &str) -> i128 {
     parse(input).into_iter().filter_map(machine_tokens).sum()
 }
@@ -97,3 +102,31 @@ pub fn part2(input: &str) -> i128 {
         .filter_map(machine_tokens)
         .sum()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(480, part1(EXAMPLE));
+    }
+}
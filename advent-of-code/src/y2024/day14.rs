@@ -16,6 +16,9 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use crate::progress;
+use crate::trace::{debug, info};
+
 type Position<T> = (T, T);
 type Velocity<T> = (T, T);
 type Robot<T> = (Position<T>, Velocity<T>);
@@ -58,6 +61,15 @@ fn parse(input: &str, grid_width: usize, grid_height: usize) -> Vec<Robot<usize>
         .collect()
 }
 
+/// Checks that the input looks like a list of robot positions and velocities.
+pub fn looks_like_input(input: &str) -> bool {
+    !input.trim().is_empty()
+        && input
+            .lines()
+            .take(10)
+            .all(|line| line.starts_with("p=") && line.contains(" v="))
+}
+
 pub fn part1(input: &str) -> usize {
     use std::cmp::Ordering::{Equal, Greater, Less};
     let grid_width = 101;
@@ -113,8 +125,7 @@ fn place_robots(robots: &[Robot<usize>], grid_width: usize, grid_height: usize)
     grid
 }
 
-#[allow(dead_code)]
-fn print_grid(grid: &[Vec<bool>]) {
+fn debug_grid(grid: &[Vec<bool>]) -> String {
     let mut s = String::with_capacity(grid.len() * (grid[0].len() + 1));
     for row in 0..grid.len() {
         #[allow(clippy::needless_range_loop)]
@@ -129,7 +140,7 @@ fn print_grid(grid: &[Vec<bool>]) {
         s += "\n";
     }
 
-    println!("{s}");
+    s
 }
 
 pub fn part2(input: &str) -> usize {
@@ -138,7 +149,12 @@ pub fn part2(input: &str) -> usize {
     let mut robots = parse(input, grid_width, grid_height);
 
     let mut t = 0;
-    while !line(&place_robots(&robots, grid_width, grid_height)) {
+    let mut grid = place_robots(&robots, grid_width, grid_height);
+
+    // the robots return to where they started after this many seconds
+    let period = (grid_width * grid_height) as u64;
+    let mut progress = progress::start("seconds simulated", Some(period));
+    while !line(&grid) {
         for robot in &mut robots {
             let ((x, y), (dx, dy)) = *robot;
             let x = (x + dx) % grid_width;
@@ -147,7 +163,11 @@ pub fn part2(input: &str) -> usize {
             *robot = ((x, y), (dx, dy));
         }
         t += 1;
+        progress.set(t as u64);
+        grid = place_robots(&robots, grid_width, grid_height);
     }
 
+    info!(seconds = t; "robots line up after {t} seconds");
+    debug!("{}", debug_grid(&grid));
     t
 }
@@ -170,6 +170,11 @@ fn parse(input: &str) -> Option<(Warehouse, Vec<CardinalDirection>)> {
     Some((warehouse, directions))
 }
 
+/// Checks that the input looks like a warehouse map followed by robot moves.
+pub fn looks_like_input(input: &str) -> bool {
+    input.starts_with('#') && input.contains('@') && input.contains("\n\n")
+}
+
 pub fn part1(input: &str) -> usize {
     let (warehouse, directions) = parse(input).expect("input parse failure");
 
@@ -16,6 +16,8 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use crate::profile;
+use crate::trace::debug;
 use crate::y2024::direction::CardinalDirection;
 use crate::y2024::maze::Maze;
 use std::collections::HashSet;
@@ -82,7 +84,6 @@ impl Point {
     }
 }
 
-#[allow(dead_code)]
 fn debug_maze(maze: &GridMaze, highlight: &HashSet<Point>) -> String {
     let height = maze.contents.len();
     let width = maze.contents[0].len();
@@ -104,6 +105,7 @@ fn debug_maze(maze: &GridMaze, highlight: &HashSet<Point>) -> String {
 }
 
 fn parse(input: &str) -> (GridMaze, Point, Point) {
+    let _span = profile::span("parse");
     let mut contents = vec![];
     let mut start: Option<Point> = None;
     let mut end: Option<Point> = None;
@@ -137,6 +139,24 @@ fn parse(input: &str) -> (GridMaze, Point, Point) {
     (maze, start, end)
 }
 
+/// Checks that the input looks like a reindeer maze.
+/// The start is always in the bottom left corner and the end in the top right.
+pub fn looks_like_input(input: &str) -> bool {
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.len() < 3 || lines[0].len() < 3 {
+        return false;
+    }
+
+    let height = lines.len();
+    let width = lines[0].len();
+
+    lines[height - 2].as_bytes().get(1) == Some(&b'S')
+        && lines[1].as_bytes().get(width - 2) == Some(&b'E')
+        && lines
+            .iter()
+            .all(|line| line.chars().all(|c| matches!(c, '#' | '.' | 'S' | 'E')))
+}
+
 /// # Panics
 pub fn part1(input: &str) -> u32 {
     let (maze, start, end) = parse(input);
@@ -173,5 +193,6 @@ pub fn part2(input: &str) -> usize {
         .map(|(_, pt)| pt)
         .collect();
 
+    debug!("tiles on a best path:\n{}", debug_maze(&maze, &nodes));
     nodes.len()
 }
@@ -176,6 +176,11 @@ fn run(mut register: Register<u64>, instructions: &[Instruction]) -> Vec<u64> {
     out
 }
 
+/// Checks that the input starts with the chronospatial computer's registers.
+pub fn looks_like_input(input: &str) -> bool {
+    input.starts_with("Register A: ")
+}
+
 /// # Panics
 pub fn part1(input: &str) -> String {
     let (registers, instructions) = parse(input).expect("Failed to parse input");
@@ -225,3 +230,21 @@ pub fn part2(input: &str) -> u64 {
 
     min_solution.unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", part1(EXAMPLE));
+    }
+}
@@ -16,7 +16,11 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use crate::progress;
+use crate::trace::debug;
+use crate::y2024::graph::UnionFind;
+use crate::y2024::maze::Maze;
+use std::collections::HashSet;
 
 fn parse(input: &str) -> Option<Vec<(usize, usize)>> {
     let mut pts = vec![];
@@ -31,8 +35,12 @@ fn parse(input: &str) -> Option<Vec<(usize, usize)>> {
     Some(pts)
 }
 
-#[allow(dead_code)]
-fn debug_grid(blocks: &[(usize, usize)], path: &[(usize, usize)], width: usize, height: usize) {
+fn debug_grid(
+    blocks: &[(usize, usize)],
+    path: &[(usize, usize)],
+    width: usize,
+    height: usize,
+) -> String {
     let mut s = String::with_capacity(height * (width + 1));
     for row in 0..height {
         for col in 0..width {
@@ -47,64 +55,122 @@ fn debug_grid(blocks: &[(usize, usize)], path: &[(usize, usize)], width: usize,
         }
         s.push('\n');
     }
-    println!("{s}");
+
+    s
+}
+
+/// The memory space as a maze, with a move to each open space next to a position.
+struct MemorySpace {
+    corrupted: HashSet<(usize, usize)>,
+    goal: (usize, usize),
+}
+
+impl Maze for MemorySpace {
+    type Node = (usize, usize);
+    fn neighbors(&self, &(x, y): &Self::Node) -> Vec<(u32, Self::Node)> {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter_map(|(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(|&(x, y)| x <= self.goal.0 && y <= self.goal.1)
+            .filter(|p| !self.corrupted.contains(p))
+            .map(|p| (1, p))
+            .collect()
+    }
 }
 
 fn shortest_path(
     corrupted_memory: &[(usize, usize)],
     goal: (usize, usize),
 ) -> Option<Vec<(usize, usize)>> {
-    let start = (0, 0);
+    let maze = MemorySpace {
+        corrupted: corrupted_memory.iter().copied().collect(),
+        goal,
+    };
 
-    let mut visited = HashSet::new();
-    visited.insert(start);
+    let mut search = maze.bfs((0, 0));
+    search.find(|v| v.node == goal)?;
+    let path = search.path_to(&goal)?;
 
-    let mut parent: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    debug!(
+        bytes = corrupted_memory.len();
+        "path past {} bytes:\n{}",
+        corrupted_memory.len(),
+        debug_grid(corrupted_memory, &path, goal.0 + 1, goal.1 + 1)
+    );
 
-    let mut queue = VecDeque::new();
-    queue.push_back(start);
+    Some(path)
+}
 
-    while let Some(position @ (x, y)) = queue.pop_front() {
-        if position == goal {
-            break;
-        }
+fn shortest_distance(corrupted_memory: &[(usize, usize)], goal: (usize, usize)) -> Option<u32> {
+    let maze = MemorySpace {
+        corrupted: corrupted_memory.iter().copied().collect(),
+        goal,
+    };
+    let (distances, _) = maze.dijkstra(&(0, 0));
 
-        let offsets = vec![(-1, 0), (1, 0), (0, -1), (0, 1)];
-        for (dx, dy) in offsets {
-            let Some(x2) = x.checked_add_signed(dx) else {
-                continue;
-            };
-            let Some(y2) = y.checked_add_signed(dy) else {
-                continue;
-            };
-            if x2 > goal.0 || y2 > goal.1 {
-                continue;
-            }
+    distances.get(&goal).copied()
+}
+
+/// Finds the first byte that cuts the start off from the goal by clearing the bytes in reverse,
+/// joining each cleared space to the open spaces around it, until the start and goal are joined.
+/// Returns `None` if the start and goal are still joined once every byte has fallen.
+fn first_blocking_byte(
+    corrupted_memory: &[(usize, usize)],
+    goal: (usize, usize),
+) -> Option<(usize, usize)> {
+    let width = goal.0 + 1;
+    let index = |(x, y): (usize, usize)| y * width + x;
 
-            let p = (x2, y2);
+    let mut corrupted = vec![false; width * (goal.1 + 1)];
+    for &p in corrupted_memory {
+        corrupted[index(p)] = true;
+    }
 
-            if visited.contains(&p) || corrupted_memory.contains(&p) {
-                continue;
+    let mut sets = UnionFind::new(corrupted.len());
+    let clear = |sets: &mut UnionFind, corrupted: &[bool], (x, y): (usize, usize)| {
+        let neighbors = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for n in neighbors {
+            if n.0 <= goal.0 && n.1 <= goal.1 && !corrupted[index(n)] {
+                sets.union(index((x, y)), index(n));
             }
+        }
+    };
 
-            visited.insert(p);
-            parent.insert(p, position);
-            queue.push_back(p);
+    for y in 0..=goal.1 {
+        for x in 0..=goal.0 {
+            if !corrupted[index((x, y))] {
+                clear(&mut sets, &corrupted, (x, y));
+            }
         }
     }
 
-    // now to count the steps for the path
-    let mut path = vec![goal];
-    let mut end = goal;
-    while end != start {
-        end = *parent.get(&end)?;
-        path.push(end);
+    if sets.connected(index((0, 0)), index(goal)) {
+        return None;
     }
 
-    path.reverse();
-    // debug_grid(corrupted_memory, path, goal.0 + 1, goal.1 + 1);
+    for &p in corrupted_memory.iter().rev() {
+        corrupted[index(p)] = false;
+        clear(&mut sets, &corrupted, p);
+        if sets.connected(index((0, 0)), index(goal)) {
+            return Some(p);
+        }
+    }
 
-    Some(path)
+    None
+}
+
+/// Checks that the input looks like a list of falling byte coordinates.
+pub fn looks_like_input(input: &str) -> bool {
+    input.lines().nth(1).is_some()
+        && input.lines().take(10).all(|line| {
+            line.split_once(',')
+                .is_some_and(|(x, y)| x.parse::<usize>().is_ok() && y.parse::<usize>().is_ok())
+        })
 }
 
 /// # Panics
@@ -127,7 +193,102 @@ pub fn part2(input: &str) -> String {
     let pts = parse(input).expect("Failed to parse input");
 
     let time_steps: Vec<usize> = (1..pts.len()).collect();
-    let blocked = time_steps.partition_point(|&i| shortest_path(&pts[0..i], goal).is_some());
+
+    // a binary search takes about log2(n) searches
+    let searches = u64::from(usize::BITS - time_steps.len().leading_zeros());
+    let mut progress = progress::start("paths searched", Some(searches));
+    let blocked = time_steps.partition_point(|&i| {
+        progress.inc();
+        shortest_path(&pts[0..i], goal).is_some()
+    });
 
     format!("{},{}", pts[blocked].0, pts[blocked].1)
 }
+
+/// # Panics
+pub fn part1_dijkstra(input: &str) -> u32 {
+    let goal = (70, 70);
+    let num_bytes = 1024;
+
+    let pts = parse(input).expect("Failed to parse input");
+    shortest_distance(&pts[0..num_bytes], goal).expect("No path found")
+}
+
+/// # Panics
+pub fn part2_dijkstra(input: &str) -> String {
+    let goal = (70, 70);
+    let pts = parse(input).expect("Failed to parse input");
+
+    let time_steps: Vec<usize> = (1..pts.len()).collect();
+    let blocked = time_steps.partition_point(|&i| shortest_distance(&pts[0..i], goal).is_some());
+
+    format!("{},{}", pts[blocked].0, pts[blocked].1)
+}
+
+/// # Panics
+pub fn part2_union_find(input: &str) -> String {
+    let goal = (70, 70);
+    let pts = parse(input).expect("Failed to parse input");
+
+    let (x, y) = first_blocking_byte(&pts, goal).expect("No byte blocks the path");
+    format!("{x},{y}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn test_shortest_path() {
+        let pts = parse(EXAMPLE).unwrap();
+        let goal = (6, 6);
+
+        let path = shortest_path(&pts[0..12], goal).unwrap();
+        assert_eq!(path.len() - 1, 22);
+        assert_eq!(shortest_distance(&pts[0..12], goal), Some(22));
+
+        // the example's path is first cut off by the byte at 6,1
+        let blocked = pts.iter().position(|&p| p == (6, 1)).unwrap();
+        assert!(shortest_path(&pts[0..blocked], goal).is_some());
+        assert!(shortest_path(&pts[0..=blocked], goal).is_none());
+        assert!(shortest_distance(&pts[0..blocked], goal).is_some());
+        assert!(shortest_distance(&pts[0..=blocked], goal).is_none());
+        assert_eq!(first_blocking_byte(&pts, goal), Some((6, 1)));
+    }
+
+    #[test]
+    fn test_no_blocking_byte() {
+        let pts = parse(EXAMPLE).unwrap();
+        let blocked = pts.iter().position(|&p| p == (6, 1)).unwrap();
+        assert_eq!(first_blocking_byte(&pts[0..blocked], (6, 6)), None);
+        assert_eq!(first_blocking_byte(&[], (6, 6)), None);
+    }
+}
@@ -136,6 +136,18 @@ fn count_towel_pattern(
     count
 }
 
+/// Checks that the input starts with a line of towel patterns followed by a blank line.
+pub fn looks_like_input(input: &str) -> bool {
+    let mut lines = input.lines();
+    let towels = lines.next().unwrap_or_default();
+
+    !towels.is_empty()
+        && towels
+            .chars()
+            .all(|c| matches!(c, 'w' | 'u' | 'b' | 'r' | 'g' | ',' | ' '))
+        && lines.next() == Some("")
+}
+
 /// # Panics
 pub fn part1(input: &str) -> usize {
     let (towels, designs) = parse(input).expect("Failed to parse input");
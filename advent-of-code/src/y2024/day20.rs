@@ -16,6 +16,8 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use crate::y2024::traversal::Bfs;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Cell {
     Space,
@@ -81,32 +83,16 @@ fn path(
     end: (usize, usize),
 ) -> Option<Vec<(usize, usize)>> {
     // find the path, but dont cheat
-    // since there is a single path, we just need to find the connection
-    let mut path = vec![start];
-    while path.last() != Some(&end) && !path.is_empty() {
-        let &current = path.last().unwrap();
-        let offsets: Vec<(isize, isize)> = vec![(1, 0), (-1, 0), (0, 1), (0, -1)];
-
-        let mut found = false;
-        for offset in offsets {
-            if let Some(possible) = position(current, offset) {
-                if path.contains(&possible) {
-                    continue;
-                }
-
-                if let Some(Cell::Space) = cell(maze, possible) {
-                    path.push(possible);
-                    found = true;
-                }
-            }
-        }
-
-        if !found {
-            return None;
-        }
-    }
-
-    Some(path)
+    let offsets: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+    let mut search = Bfs::new(start, |&current| {
+        offsets
+            .into_iter()
+            .filter_map(move |offset| position(current, offset))
+            .filter(|&p| cell(maze, p) == Some(Cell::Space))
+    });
+
+    search.find(|v| v.node == end)?;
+    search.path_to(&end)
 }
 
 fn count_cheats(path: &[(usize, usize)], max_offset: usize, min_savings: usize) -> usize {
@@ -132,6 +118,15 @@ fn count_cheats(path: &[(usize, usize)], max_offset: usize, min_savings: usize)
     count
 }
 
+/// Checks that the input looks like a racetrack with a single start and end.
+pub fn looks_like_input(input: &str) -> bool {
+    input.chars().filter(|&c| c == 'S').count() == 1
+        && input.chars().filter(|&c| c == 'E').count() == 1
+        && input
+            .lines()
+            .all(|line| line.chars().all(|c| matches!(c, '#' | '.' | 'S' | 'E')))
+}
+
 /// # Panics
 pub fn part1(input: &str) -> usize {
     let (start, end, maze) = parse(input);
@@ -151,3 +146,39 @@ pub fn part2(input: &str) -> usize {
 
     count_cheats(&positions, max_offset, min_speed_savings)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[test]
+    fn test_count_cheats() {
+        let (start, end, maze) = parse(EXAMPLE);
+        let positions = path(&maze, start, end).unwrap();
+        assert_eq!(positions.len() - 1, 84);
+
+        let tests = vec![(2, 64, 1), (2, 40, 2), (20, 76, 3), (20, 74, 7)];
+        for (max_offset, min_savings, expected) in tests {
+            let count = count_cheats(&positions, max_offset, min_savings);
+            assert_eq!(count, expected, "{max_offset} {min_savings}");
+        }
+    }
+}
@@ -171,6 +171,14 @@ fn solution(input: &str, num_robots: usize) -> usize {
         .sum()
 }
 
+/// Checks that the input looks like door codes such as `029A`.
+pub fn looks_like_input(input: &str) -> bool {
+    !input.trim().is_empty()
+        && input.lines().all(|line| {
+            line.len() == 4 && line.ends_with('A') && line[..3].chars().all(|c| c.is_ascii_digit())
+        })
+}
+
 /// # Panics
 pub fn part1(input: &str) -> usize {
     // 1 robot on numpad, 2 on keypad
@@ -84,6 +84,15 @@ fn sequence_totals(monkeys: &[usize], lookahead: usize) -> Vec<usize> {
     sequence_bananas
 }
 
+/// Checks that the input looks like one initial secret number per line.
+pub fn looks_like_input(input: &str) -> bool {
+    input.lines().nth(1).is_some()
+        && input
+            .lines()
+            .take(10)
+            .all(|line| line.parse::<usize>().is_ok())
+}
+
 pub fn part1(input: &str) -> usize {
     input
         .lines()
@@ -16,7 +16,11 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use crate::y2024::graph::{AdjacencyList, Graph, max_cliques};
+use crate::dot;
+use crate::y2024::graph::{
+    AdjacencyList, BitAdjacencyMatrix, Dot, Graph, for_each_k_clique, max_cliques,
+    max_cliques_bitset,
+};
 
 fn network_to_adjacency_list(input: &str) -> AdjacencyList<&str> {
     input.lines().filter_map(|l| l.split_once('-')).fold(
@@ -28,7 +32,30 @@ fn network_to_adjacency_list(input: &str) -> AdjacencyList<&str> {
     )
 }
 
+/// Checks that the input looks like a list of connections between two letter computer names.
+pub fn looks_like_input(input: &str) -> bool {
+    !input.trim().is_empty()
+        && input.lines().take(10).all(|line| {
+            line.split_once('-')
+                .is_some_and(|(a, b)| a.len() == 2 && b.len() == 2)
+        })
+}
+
 pub fn part1(input: &str) -> usize {
+    let graph = BitAdjacencyMatrix::from_graph(&network_to_adjacency_list(input));
+
+    let mut count = 0usize;
+    for_each_k_clique(&graph, 3, |triangle| {
+        if triangle.iter().any(|&i| graph.vertex(i).starts_with('t')) {
+            count += 1;
+        }
+    });
+
+    count
+}
+
+/// Counts the triangles by looping over each vertex's larger neighbors.
+pub fn part1_loops(input: &str) -> usize {
     let graph: AdjacencyList<&str> = network_to_adjacency_list(input);
 
     let mut count = 0usize;
@@ -48,7 +75,67 @@ pub fn part1(input: &str) -> usize {
 }
 
 pub fn part2(input: &str) -> String {
+    let graph = BitAdjacencyMatrix::from_graph(&network_to_adjacency_list(input));
+    let cliques = max_cliques_bitset(&graph);
+    dot::export(|| {
+        Dot::new("LAN party")
+            .highlight_clique(&cliques[0])
+            .render(&graph)
+    });
+    cliques[0].join(",")
+}
+
+/// Finds the password with Bron–Kerbosch on hash sets of neighbors.
+pub fn part2_hash_set(input: &str) -> String {
     let graph = network_to_adjacency_list(input);
     let cliques = max_cliques(&graph);
     cliques[0].join(",")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE), 7);
+        assert_eq!(part1_loops(EXAMPLE), 7);
+        assert_eq!(part2(EXAMPLE), "co,de,ka,ta");
+        assert_eq!(part2_hash_set(EXAMPLE), "co,de,ka,ta");
+    }
+}
@@ -16,6 +16,8 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use crate::dot;
+use crate::y2024::graph::{Cycle, DirectedAdjacencyList, Dot, find_cycle};
 use core::panic;
 use std::{
     collections::{HashMap, HashSet, VecDeque},
@@ -138,6 +140,32 @@ impl<'a> Adder<'a> {
         deps
     }
 
+    /// The wires as a graph with an edge from each gate input to its output.
+    fn network(&self) -> DirectedAdjacencyList<&'a str> {
+        let mut graph = DirectedAdjacencyList::new();
+        for (&out, &Expr(_, lhs, rhs)) in &self.var_to_expr {
+            graph.add_edge(lhs, out);
+            graph.add_edge(rhs, out);
+        }
+
+        graph
+    }
+
+    /// Finds wires that feed back into themselves among the ones `var` depends on, which keep it
+    /// from being evaluated.
+    fn cycle(&self, var: &'a str) -> Option<Cycle<&'a str>> {
+        let mut upstream = HashSet::from([var]);
+        let mut walk = vec![var];
+        while let Some(w) = walk.pop() {
+            if let Some(&Expr(_, lhs, rhs)) = self.var_to_expr.get(w) {
+                walk.extend([lhs, rhs].into_iter().filter(|&v| upstream.insert(v)));
+            }
+        }
+
+        let upstream: Vec<_> = upstream.into_iter().collect();
+        find_cycle(&self.network().induced_subgraph(&upstream))
+    }
+
     fn swap(mut self, gate1: &'a str, gate2: &'a str) -> Self {
         let expr1 = self.var_to_expr[gate1];
         let expr2 = self.var_to_expr[gate2];
@@ -272,6 +300,11 @@ fn parse(input: &str) -> Option<(Cache<'_>, Adder<'_>)> {
     Some((cache, adder))
 }
 
+/// Checks that the input starts with the initial wire values.
+pub fn looks_like_input(input: &str) -> bool {
+    input.starts_with("x00: ") && input.contains(" -> ")
+}
+
 /// # Panics
 pub fn part1(input: &str) -> usize {
     let (mut cache, adder) = parse(input).expect("Failed to parse input");
@@ -281,7 +314,12 @@ pub fn part1(input: &str) -> usize {
     for (i, var) in zs.iter().enumerate() {
         let v = adder
             .eval(&mut cache, var)
-            .unwrap_or_else(|| panic!("Failed to evaluate z{i:02} bit"));
+            .unwrap_or_else(|| match adder.cycle(var) {
+                Some(cycle) => {
+                    panic!("Failed to evaluate z{i:02} bit, wires are cyclic between {cycle}")
+                }
+                None => panic!("Failed to evaluate z{i:02} bit"),
+            });
         ans += (v as usize) << i;
     }
 
@@ -292,11 +330,28 @@ pub fn part1(input: &str) -> usize {
 pub fn part2(input: &str) -> String {
     let (_, adder) = parse(input).expect("Failed to parse input");
 
+    // repairing consumes the adder, so keep the original to draw only if it will be drawn
+    let original = dot::enabled().then(|| adder.clone());
+
     let mut swaps = vec![];
     let fixable = repair(adder, 0, &mut swaps, 4);
     if !fixable {
         panic!("Adder provided by input not fixable in 4 swaps");
     }
+    if let Some(adder) = original {
+        dot::export(|| {
+            let labels = |v: &&str| match adder.var_to_expr.get(v) {
+                Some(Expr(op, _, _)) => format!("{v}\n{}", format!("{op:?}").to_uppercase()),
+                None => v.to_string(),
+            };
+            let wires: Vec<&str> = swaps.iter().flat_map(|&(a, b)| [a, b]).collect();
+            Dot::new("crossed wires")
+                .highlight_vertices(&wires)
+                .vertex_label(labels)
+                .render_directed(&adder.network())
+        });
+    }
+
     let mut ans = vec![];
     for (a, b) in swaps {
         ans.push(a);
@@ -306,3 +361,44 @@ pub fn part2(input: &str) -> String {
     ans.sort_unstable();
     ans.join(",")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_message() {
+        // z01 and z02 both depend on a cycle, and z01 fails first
+        let input = "\
+x00: 1
+x01: 0
+x02: 1
+y00: 1
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR aaa -> z01
+bbb OR y01 -> aaa
+aaa AND x01 -> bbb
+y02 XOR ccc -> z02
+ddd OR x02 -> ccc
+ccc AND y02 -> ddd
+";
+
+        let panic = std::panic::catch_unwind(|| part1(input)).unwrap_err();
+        let message = panic.downcast::<String>().unwrap();
+        assert!(
+            message.starts_with("Failed to evaluate z01 bit"),
+            "{message}"
+        );
+        assert!(
+            message.contains("aaa") && message.contains("bbb"),
+            "{message}"
+        );
+        assert!(
+            !message.contains("ccc") && !message.contains("ddd"),
+            "{message}"
+        );
+    }
+}
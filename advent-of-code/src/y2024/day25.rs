@@ -47,6 +47,17 @@ fn parse(input: &str) -> (Locks, Keys) {
     (locks, keys)
 }
 
+/// Checks that the input starts with a five pin lock or key schematic.
+pub fn looks_like_input(input: &str) -> bool {
+    let schematic: Vec<&str> = input.lines().take_while(|l| !l.is_empty()).collect();
+
+    schematic.len() == 7
+        && (schematic[0] == "#####" || schematic[0] == ".....")
+        && schematic
+            .iter()
+            .all(|row| row.len() == 5 && row.chars().all(|c| c == '#' || c == '.'))
+}
+
 pub fn part1(input: &str) -> usize {
     let num_pins = 5;
     let (locks, keys) = parse(input);
@@ -68,3 +79,55 @@ pub fn part1(input: &str) -> usize {
 pub fn part2(_input: &str) -> String {
     "No puzzle".into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...#.
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(3, part1(EXAMPLE));
+    }
+}
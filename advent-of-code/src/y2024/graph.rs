@@ -16,10 +16,15 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use crate::y2024::traversal::{Bfs, Dfs};
+use std::fmt;
 use std::hash::Hash;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 
+/// A graph whose edges are followed from a vertex to its neighbors.
+///
+/// In a directed graph the neighbors of a vertex are the ones its edges point to.
 pub trait Graph {
     type Vertex: Hash;
 
@@ -27,6 +32,78 @@ pub trait Graph {
     fn neighbors(&self, v: &Self::Vertex) -> Vec<Self::Vertex>;
     fn neighbors_set(&self, v: &Self::Vertex) -> HashSet<Self::Vertex>;
     fn vertices(&self) -> Vec<Self::Vertex>;
+
+    /// Every edge as a pair of the vertex it leaves and the vertex it enters.
+    /// Undirected graphs yield each edge once.
+    fn edges(&self) -> Vec<(Self::Vertex, Self::Vertex)>
+    where
+        Self::Vertex: Clone,
+    {
+        self.vertices()
+            .into_iter()
+            .flat_map(|v| self.neighbors(&v).into_iter().map(move |n| (v.clone(), n)))
+            .collect()
+    }
+
+    /// Visits the vertices reachable from `start` breadth first.
+    fn bfs(
+        &self,
+        start: Self::Vertex,
+    ) -> Bfs<Self::Vertex, impl FnMut(&Self::Vertex) -> Vec<Self::Vertex>>
+    where
+        Self::Vertex: Eq + Clone,
+    {
+        Bfs::new(start, |v| self.neighbors(v))
+    }
+
+    /// Visits the vertices reachable from `start` depth first.
+    fn dfs(
+        &self,
+        start: Self::Vertex,
+    ) -> Dfs<Self::Vertex, impl FnMut(&Self::Vertex) -> Vec<Self::Vertex>>
+    where
+        Self::Vertex: Eq + Clone,
+    {
+        Dfs::new(start, |v| self.neighbors(v))
+    }
+}
+
+/// A graph whose edges point one way.
+pub trait DirectedGraph: Graph {
+    /// The vertices that edges from `v` point to, the same as [`Graph::neighbors`].
+    fn out_neighbors(&self, v: &Self::Vertex) -> Vec<Self::Vertex> {
+        self.neighbors(v)
+    }
+
+    /// The vertices with an edge pointing to `v`.
+    fn in_neighbors(&self, v: &Self::Vertex) -> Vec<Self::Vertex>;
+}
+
+/// A graph with a weight, such as a distance or capacity, on each edge.
+pub trait WeightedGraph: Graph {
+    type Weight: Copy;
+
+    fn weight(&self, v1: &Self::Vertex, v2: &Self::Vertex) -> Option<Self::Weight>;
+
+    /// Every edge along with its weight, in the same order as [`Graph::edges`].
+    fn weighted_edges(&self) -> Vec<(Self::Vertex, Self::Vertex, Self::Weight)>
+    where
+        Self::Vertex: Clone,
+    {
+        self.edges()
+            .into_iter()
+            .filter_map(|(v1, v2)| {
+                let w = self.weight(&v1, &v2)?;
+                Some((v1, v2, w))
+            })
+            .collect()
+    }
+}
+
+fn sorted<V: Ord>(vs: impl IntoIterator<Item = V>) -> Vec<V> {
+    let mut vs: Vec<V> = vs.into_iter().collect();
+    vs.sort_unstable();
+    vs
 }
 
 #[derive(Debug, Clone)]
@@ -93,6 +170,17 @@ where
         verts.sort_unstable();
         verts
     }
+
+    fn edges(&self) -> Vec<(V, V)> {
+        let edges = self
+            .contents
+            .iter()
+            .flat_map(|(v1, ns)| ns.iter().map(move |v2| (v1, v2)))
+            .filter(|(v1, v2)| v1 <= v2)
+            .map(|(v1, v2)| (v1.clone(), v2.clone()));
+
+        sorted(edges)
+    }
 }
 
 pub struct AdjacencyMatrix<V> {
@@ -162,89 +250,2085 @@ where
     fn vertices(&self) -> Vec<V> {
         self.vertex_map.iter().filter_map(|v| *v).collect()
     }
+
+    fn edges(&self) -> Vec<(V, V)> {
+        let mut edges = vec![];
+        for i1 in 0..self.max_vertices {
+            for i2 in i1..self.max_vertices {
+                if self.connection_map[i1 * self.max_vertices + i2] {
+                    let v1 = self.vertex_map[i1].expect("edge found but no vertex found");
+                    let v2 = self.vertex_map[i2].expect("edge found but no vertex found");
+                    edges.push((v1, v2));
+                }
+            }
+        }
+
+        edges
+    }
 }
 
-/// # Panics
-pub fn bron_kerbosch<G, V>(
-    graph: &G,
-    current: &HashSet<V>,
-    potential: &mut HashSet<V>,
-    excluded: &mut HashSet<V>,
-    cliques: &mut Vec<Vec<V>>,
-) where
-    G: Graph<Vertex = V>,
+/// A set of the indices `0..len`, packed one bit per index so that whole sets can be
+/// intersected a word at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// An empty set with room for the indices `0..len`.
+    #[must_use]
+    pub fn new(len: usize) -> BitSet {
+        BitSet {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    /// A set holding every index in `0..len`.
+    #[must_use]
+    pub fn full(len: usize) -> BitSet {
+        let mut set = BitSet {
+            words: vec![u64::MAX; len.div_ceil(64)],
+        };
+        if !len.is_multiple_of(64) {
+            set.words[len / 64] = (1 << (len % 64)) - 1;
+        }
+
+        set
+    }
+
+    pub fn insert(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    pub fn remove(&mut self, i: usize) {
+        self.words[i / 64] &= !(1 << (i % 64));
+    }
+
+    #[must_use]
+    pub fn contains(&self, i: usize) -> bool {
+        self.words
+            .get(i / 64)
+            .is_some_and(|w| w & (1 << (i % 64)) != 0)
+    }
+
+    /// The number of indices in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        self.zip(other, |a, b| a | b)
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        self.zip(other, |a, b| a & b)
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        self.zip(other, |a, b| a & !b)
+    }
+
+    /// The size of the intersection with `other`, without building it.
+    #[must_use]
+    pub fn intersection_len(&self, other: &BitSet) -> usize {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// The indices in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+
+    fn zip(&self, other: &BitSet, f: impl Fn(u64, u64) -> u64) -> BitSet {
+        let len = self.words.len().max(other.words.len());
+        let word = |words: &[u64], i: usize| words.get(i).copied().unwrap_or(0);
+        BitSet {
+            words: (0..len)
+                .map(|i| f(word(&self.words, i), word(&other.words, i)))
+                .collect(),
+        }
+    }
+}
+
+/// An undirected graph stored as one [`BitSet`] of neighbors per vertex, for algorithms that
+/// spend their time intersecting neighborhoods.
+#[derive(Debug, Clone)]
+pub struct BitAdjacencyMatrix<V> {
+    vertices: Vec<V>,
+    index: HashMap<V, usize>,
+    rows: Vec<BitSet>,
+}
+
+impl<V> BitAdjacencyMatrix<V>
+where
     V: Eq + Ord + Hash + Clone,
 {
-    if potential.is_empty() && excluded.is_empty() {
-        if current.len() > 2 {
-            let mut clique: Vec<V> = current.iter().cloned().collect();
-            clique.sort_unstable();
-            cliques.push(clique);
+    /// Copies the vertices and edges of `graph`. Vertices are indexed in sorted order.
+    pub fn from_graph<G: Graph<Vertex = V>>(graph: &G) -> BitAdjacencyMatrix<V> {
+        let vertices = sorted(graph.vertices());
+        let index: HashMap<V, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.clone(), i))
+            .collect();
+
+        let mut rows = vec![BitSet::new(vertices.len()); vertices.len()];
+        for (i, v) in vertices.iter().enumerate() {
+            for n in graph.neighbors(v) {
+                if let Some(&j) = index.get(&n) {
+                    rows[i].insert(j);
+                    rows[j].insert(i);
+                }
+            }
+        }
+
+        BitAdjacencyMatrix {
+            vertices,
+            index,
+            rows,
         }
-        return;
     }
 
-    let pivot = potential
-        .union(excluded)
-        .max_by_key(|v| graph.neighbors_set(v).len())
-        .cloned()
-        .expect("potential and excluded are both empty when at least one should be non-empty");
+    #[must_use]
+    pub fn index_of(&self, v: &V) -> Option<usize> {
+        self.index.get(v).copied()
+    }
 
-    let pivot_neighbors = graph.neighbors_set(&pivot);
-    let candidates: Vec<V> = potential.difference(&pivot_neighbors).cloned().collect();
-    for candidate in candidates {
-        let mut current2 = current.clone();
-        current2.insert(candidate.clone());
+    #[must_use]
+    pub fn vertex(&self, i: usize) -> &V {
+        &self.vertices[i]
+    }
 
-        let candidate_neighbors = graph.neighbors_set(&candidate);
-        let mut potential2 = potential
-            .intersection(&candidate_neighbors)
-            .cloned()
-            .collect();
+    /// The indices of the neighbors of the vertex at index `i`.
+    #[must_use]
+    pub fn row(&self, i: usize) -> &BitSet {
+        &self.rows[i]
+    }
 
-        let mut excluded2 = excluded
-            .intersection(&candidate_neighbors)
-            .cloned()
-            .collect();
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
 
-        bron_kerbosch(graph, &current2, &mut potential2, &mut excluded2, cliques);
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+}
 
-        potential.remove(&candidate);
-        excluded.insert(candidate);
+impl<V> Graph for BitAdjacencyMatrix<V>
+where
+    V: Eq + Ord + Hash + Clone,
+{
+    type Vertex = V;
+
+    fn contains_edge(&self, v1: &V, v2: &V) -> bool {
+        match (self.index_of(v1), self.index_of(v2)) {
+            (Some(i1), Some(i2)) => self.rows[i1].contains(i2),
+            _ => false,
+        }
+    }
+
+    fn neighbors(&self, v: &V) -> Vec<V> {
+        self.index_of(v)
+            .map(|i| {
+                self.rows[i]
+                    .iter()
+                    .map(|j| self.vertices[j].clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn neighbors_set(&self, v: &V) -> HashSet<V> {
+        self.neighbors(v).into_iter().collect()
+    }
+
+    fn vertices(&self) -> Vec<V> {
+        self.vertices.clone()
+    }
+
+    fn edges(&self) -> Vec<(V, V)> {
+        let mut edges = vec![];
+        for (i, row) in self.rows.iter().enumerate() {
+            for j in row.iter().filter(|&j| j >= i) {
+                edges.push((self.vertices[i].clone(), self.vertices[j].clone()));
+            }
+        }
+
+        edges
     }
 }
 
-/// # Panics
-pub fn max_cliques<G, V>(graph: &G) -> Vec<Vec<V>>
+#[derive(Debug, Clone)]
+pub struct DirectedAdjacencyList<V> {
+    outgoing: HashMap<V, HashSet<V>>,
+    incoming: HashMap<V, HashSet<V>>,
+}
+
+impl<V> DirectedAdjacencyList<V>
 where
-    G: Graph<Vertex = V>,
-    V: std::cmp::Ord + std::cmp::Eq + std::hash::Hash + Clone,
+    V: Eq + Ord + Hash + Clone,
 {
-    let mut cliques = vec![];
-    let current = HashSet::new();
-    let mut potentials = graph.vertices().into_iter().collect();
-    let mut excluded = HashSet::new();
+    pub fn new() -> DirectedAdjacencyList<V> {
+        DirectedAdjacencyList {
+            outgoing: HashMap::new(),
+            incoming: HashMap::new(),
+        }
+    }
 
-    bron_kerbosch(
-        graph,
-        &current,
-        &mut potentials,
-        &mut excluded,
-        &mut cliques,
-    );
+    pub fn add_vertex(&mut self, v: V) {
+        self.outgoing.entry(v.clone()).or_default();
+        self.incoming.entry(v).or_default();
+    }
 
-    if cliques.is_empty() {
-        return vec![];
+    /// Adds an edge pointing from `from` to `to`.
+    pub fn add_edge(&mut self, from: V, to: V) {
+        self.add_vertex(from.clone());
+        self.add_vertex(to.clone());
+        self.outgoing
+            .entry(from.clone())
+            .or_default()
+            .insert(to.clone());
+        self.incoming.entry(to).or_default().insert(from);
     }
 
-    let largest_size = cliques
-        .iter()
-        .map(Vec::len)
-        .max()
-        .expect("cliques should be non-empty");
+    /// The graph of just `vertices` and the edges between them.
+    #[must_use]
+    pub fn induced_subgraph(&self, vertices: &[V]) -> DirectedAdjacencyList<V> {
+        let included: HashSet<&V> = vertices.iter().collect();
 
-    cliques
-        .into_iter()
-        .filter(|c| c.len() == largest_size)
-        .collect()
+        let mut subgraph = DirectedAdjacencyList::new();
+        for v in vertices {
+            subgraph.add_vertex(v.clone());
+            for n in self.outgoing.get(v).into_iter().flatten() {
+                if included.contains(n) {
+                    subgraph.add_edge(v.clone(), n.clone());
+                }
+            }
+        }
+
+        subgraph
+    }
+}
+
+impl<V> Default for DirectedAdjacencyList<V>
+where
+    V: Eq + Ord + Hash + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Graph for DirectedAdjacencyList<V>
+where
+    V: Eq + Ord + Hash + Clone,
+{
+    type Vertex = V;
+    fn contains_edge(&self, v1: &V, v2: &V) -> bool {
+        self.outgoing.get(v1).is_some_and(|es| es.contains(v2))
+    }
+
+    fn neighbors(&self, v: &V) -> Vec<V> {
+        self.outgoing
+            .get(v)
+            .map(|ns| sorted(ns.iter().cloned()))
+            .unwrap_or_default()
+    }
+
+    fn neighbors_set(&self, v: &V) -> HashSet<V> {
+        self.outgoing.get(v).cloned().unwrap_or_default()
+    }
+
+    fn vertices(&self) -> Vec<V> {
+        sorted(self.outgoing.keys().cloned())
+    }
+}
+
+impl<V> DirectedGraph for DirectedAdjacencyList<V>
+where
+    V: Eq + Ord + Hash + Clone,
+{
+    fn in_neighbors(&self, v: &V) -> Vec<V> {
+        self.incoming
+            .get(v)
+            .map(|ns| sorted(ns.iter().cloned()))
+            .unwrap_or_default()
+    }
+}
+
+/// A directed graph with a weight on each edge.
+/// An undirected weighted graph can be built with [`WeightedAdjacencyList::add_undirected_edge`].
+#[derive(Debug, Clone)]
+pub struct WeightedAdjacencyList<V, W> {
+    outgoing: HashMap<V, HashMap<V, W>>,
+    incoming: HashMap<V, HashSet<V>>,
+}
+
+impl<V, W> WeightedAdjacencyList<V, W>
+where
+    V: Eq + Ord + Hash + Clone,
+    W: Copy,
+{
+    pub fn new() -> WeightedAdjacencyList<V, W> {
+        WeightedAdjacencyList {
+            outgoing: HashMap::new(),
+            incoming: HashMap::new(),
+        }
+    }
+
+    pub fn add_vertex(&mut self, v: V) {
+        self.outgoing.entry(v.clone()).or_default();
+        self.incoming.entry(v).or_default();
+    }
+
+    /// Adds an edge pointing from `from` to `to`, replacing the weight of an existing one.
+    pub fn add_edge(&mut self, from: V, to: V, weight: W) {
+        self.add_vertex(from.clone());
+        self.add_vertex(to.clone());
+        self.outgoing
+            .entry(from.clone())
+            .or_default()
+            .insert(to.clone(), weight);
+        self.incoming.entry(to).or_default().insert(from);
+    }
+
+    /// Adds edges both ways between `v1` and `v2` with the same weight.
+    pub fn add_undirected_edge(&mut self, v1: V, v2: V, weight: W) {
+        self.add_edge(v1.clone(), v2.clone(), weight);
+        self.add_edge(v2, v1, weight);
+    }
+}
+
+impl<V, W> Default for WeightedAdjacencyList<V, W>
+where
+    V: Eq + Ord + Hash + Clone,
+    W: Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, W> Graph for WeightedAdjacencyList<V, W>
+where
+    V: Eq + Ord + Hash + Clone,
+    W: Copy,
+{
+    type Vertex = V;
+    fn contains_edge(&self, v1: &V, v2: &V) -> bool {
+        self.outgoing.get(v1).is_some_and(|es| es.contains_key(v2))
+    }
+
+    fn neighbors(&self, v: &V) -> Vec<V> {
+        self.outgoing
+            .get(v)
+            .map(|ns| sorted(ns.keys().cloned()))
+            .unwrap_or_default()
+    }
+
+    fn neighbors_set(&self, v: &V) -> HashSet<V> {
+        self.outgoing
+            .get(v)
+            .map(|ns| ns.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn vertices(&self) -> Vec<V> {
+        sorted(self.outgoing.keys().cloned())
+    }
+}
+
+impl<V, W> DirectedGraph for WeightedAdjacencyList<V, W>
+where
+    V: Eq + Ord + Hash + Clone,
+    W: Copy,
+{
+    fn in_neighbors(&self, v: &V) -> Vec<V> {
+        self.incoming
+            .get(v)
+            .map(|ns| sorted(ns.iter().cloned()))
+            .unwrap_or_default()
+    }
+}
+
+impl<V, W> WeightedGraph for WeightedAdjacencyList<V, W>
+where
+    V: Eq + Ord + Hash + Clone,
+    W: Copy,
+{
+    type Weight = W;
+    fn weight(&self, v1: &V, v2: &V) -> Option<W> {
+        self.outgoing.get(v1)?.get(v2).copied()
+    }
+}
+
+/// A cycle of directed edges, starting and ending with the same vertex.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<V>(pub Vec<V>);
+
+impl<V: fmt::Display> fmt::Display for Cycle<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vertices: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", vertices.join(" → "))
+    }
+}
+
+impl<V: fmt::Debug + fmt::Display> std::error::Error for Cycle<V> {}
+
+/// Orders the vertices so every edge points from an earlier vertex to a later one, using Kahn's
+/// algorithm, or finds a cycle that makes that impossible.
+///
+/// # Panics
+/// Panics if a neighbor isn't one of the graph's vertices.
+pub fn topological_sort<G, V>(graph: &G) -> Result<Vec<V>, Cycle<V>>
+where
+    G: DirectedGraph<Vertex = V>,
+    V: Eq + Hash + Clone,
+{
+    let vertices = graph.vertices();
+    let mut in_degree: HashMap<V, usize> = vertices
+        .iter()
+        .map(|v| (v.clone(), graph.in_neighbors(v).len()))
+        .collect();
+
+    let mut ready: VecDeque<V> = vertices
+        .iter()
+        .filter(|&v| in_degree[v] == 0)
+        .cloned()
+        .collect();
+
+    let mut order = vec![];
+    while let Some(v) = ready.pop_front() {
+        for n in graph.out_neighbors(&v) {
+            let degree = in_degree.get_mut(&n).expect("neighbor is not a vertex");
+            *degree -= 1;
+            if *degree == 0 {
+                ready.push_back(n);
+            }
+        }
+        order.push(v);
+    }
+
+    if order.len() == vertices.len() {
+        return Ok(order);
+    }
+
+    // every vertex left has an edge from another vertex left,
+    // so walking those edges backwards has to come around to a vertex again
+    let left = |v: &V| in_degree[v] > 0;
+    let mut path: Vec<V> = vertices.into_iter().filter(left).take(1).collect();
+    loop {
+        let last = path.last().expect("path starts with a vertex");
+        let previous = graph
+            .in_neighbors(last)
+            .into_iter()
+            .find(left)
+            .expect("vertex left has no edge from another vertex left");
+
+        if let Some(start) = path.iter().position(|v| *v == previous) {
+            let mut cycle = path.split_off(start);
+            cycle.push(previous);
+            cycle.reverse();
+            return Err(Cycle(cycle));
+        }
+        path.push(previous);
+    }
+}
+
+/// Orders the vertices like [`topological_sort`], using a depth first search instead.
+pub fn topological_sort_dfs<G, V>(graph: &G) -> Result<Vec<V>, Cycle<V>>
+where
+    G: DirectedGraph<Vertex = V>,
+    V: Eq + Hash + Clone,
+{
+    // vertices map to whether every vertex after them has been ordered
+    let mut finished: HashMap<V, bool> = HashMap::new();
+    let mut order = vec![];
+
+    for root in graph.vertices() {
+        if finished.contains_key(&root) {
+            continue;
+        }
+
+        finished.insert(root.clone(), false);
+        let mut stack = vec![(root.clone(), graph.out_neighbors(&root))];
+        while let Some((v, ns)) = stack.last_mut() {
+            let Some(n) = ns.pop() else {
+                let v = v.clone();
+                stack.pop();
+                finished.insert(v.clone(), true);
+                order.push(v);
+                continue;
+            };
+
+            match finished.get(&n) {
+                Some(true) => {}
+                Some(false) => {
+                    // n is on the stack, so the stack from n onwards leads back to it
+                    let start = stack.iter().position(|(u, _)| *u == n).unwrap_or(0);
+                    let mut cycle: Vec<V> = stack[start..].iter().map(|(u, _)| u.clone()).collect();
+                    cycle.push(n);
+                    return Err(Cycle(cycle));
+                }
+                None => {
+                    finished.insert(n.clone(), false);
+                    let ns = graph.out_neighbors(&n);
+                    stack.push((n, ns));
+                }
+            }
+        }
+    }
+
+    order.reverse();
+    Ok(order)
+}
+
+/// Finds a cycle of edges, if there is one. The cycle starts and ends with the same vertex.
+pub fn find_cycle<G, V>(graph: &G) -> Option<Cycle<V>>
+where
+    G: DirectedGraph<Vertex = V>,
+    V: Eq + Hash + Clone,
+{
+    topological_sort_dfs(graph).err()
+}
+
+/// Groups the vertices into the largest sets where every vertex has a path to every other,
+/// using Tarjan's algorithm. A component comes before any component with an edge into it, so
+/// the components are in the reverse of a topological order.
+pub fn strongly_connected_components<G, V>(graph: &G) -> Vec<Vec<V>>
+where
+    G: DirectedGraph<Vertex = V>,
+    V: Eq + Hash + Clone,
+{
+    // each vertex's order of discovery and the earliest discovered vertex on the stack it reaches
+    let mut index: HashMap<V, usize> = HashMap::new();
+    let mut low: HashMap<V, usize> = HashMap::new();
+    let mut stack: Vec<V> = vec![];
+    let mut on_stack: HashSet<V> = HashSet::new();
+    let mut components = vec![];
+
+    for root in graph.vertices() {
+        if index.contains_key(&root) {
+            continue;
+        }
+
+        let mut calls = vec![(root, vec![])];
+        let mut discovered = true;
+        while let Some((v, ns)) = calls.last_mut() {
+            let v = v.clone();
+            if std::mem::take(&mut discovered) {
+                index.insert(v.clone(), index.len());
+                low.insert(v.clone(), index[&v]);
+                stack.push(v.clone());
+                on_stack.insert(v.clone());
+                *ns = graph.out_neighbors(&v);
+            }
+
+            if let Some(n) = ns.pop() {
+                if !index.contains_key(&n) {
+                    calls.push((n, vec![]));
+                    discovered = true;
+                } else if on_stack.contains(&n) {
+                    let lowest = low[&v].min(index[&n]);
+                    low.insert(v, lowest);
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some((parent, _)) = calls.last() {
+                let lowest = low[parent].min(low[&v]);
+                low.insert(parent.clone(), lowest);
+            }
+
+            if low[&v] == index[&v] {
+                let mut component = vec![];
+                while let Some(u) = stack.pop() {
+                    on_stack.remove(&u);
+                    let done = u == v;
+                    component.push(u);
+                    if done {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+/// Finds the same components as [`strongly_connected_components`] using Kosaraju's algorithm,
+/// which orders them topologically instead.
+pub fn strongly_connected_components_kosaraju<G, V>(graph: &G) -> Vec<Vec<V>>
+where
+    G: DirectedGraph<Vertex = V>,
+    V: Eq + Hash + Clone,
+{
+    // order the vertices by when their depth first search finished
+    let mut visited: HashSet<V> = HashSet::new();
+    let mut finished = vec![];
+    for root in graph.vertices() {
+        if !visited.insert(root.clone()) {
+            continue;
+        }
+
+        let mut stack = vec![(root.clone(), graph.out_neighbors(&root))];
+        while let Some((v, ns)) = stack.last_mut() {
+            match ns.pop() {
+                Some(n) => {
+                    if visited.insert(n.clone()) {
+                        let ns = graph.out_neighbors(&n);
+                        stack.push((n, ns));
+                    }
+                }
+                None => {
+                    finished.push(v.clone());
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    // the last to finish is in a source component, and following edges backwards from it
+    // reaches only its own component
+    let mut assigned: HashSet<V> = HashSet::new();
+    let mut components = vec![];
+    for root in finished.into_iter().rev() {
+        if !assigned.insert(root.clone()) {
+            continue;
+        }
+
+        let mut component = vec![];
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            for n in graph.in_neighbors(&v) {
+                if assigned.insert(n.clone()) {
+                    stack.push(n);
+                }
+            }
+            component.push(v);
+        }
+        components.push(component);
+    }
+
+    components
+}
+
+/// Checks that no edge points from a vertex in `sequence` to one earlier in it.
+pub fn is_consistent<G, V>(graph: &G, sequence: &[V]) -> bool
+where
+    G: Graph<Vertex = V>,
+{
+    sequence.iter().enumerate().all(|(i, later)| {
+        sequence[..i]
+            .iter()
+            .all(|earlier| !graph.contains_edge(later, earlier))
+    })
+}
+
+/// Disjoint sets of the indices `0..len`, which can be merged and queried for whether two
+/// indices are in the same set.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// Starts with each index in a set of its own.
+    #[must_use]
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            sets: len,
+        }
+    }
+
+    /// The index representing the set `x` is in.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // point everything on the way at the root so later finds are quicker
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets `a` and `b` are in, returning whether they were separate sets.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let a = self.find(a);
+        let b = self.find(b);
+        if a == b {
+            return false;
+        }
+
+        // hang the shallower tree under the deeper one
+        let (child, root) = if self.rank[a] < self.rank[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[child] = root;
+        if self.rank[a] == self.rank[b] {
+            self.rank[root] += 1;
+        }
+
+        self.sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The number of disjoint sets.
+    #[must_use]
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+}
+
+/// Groups the vertices that are connected by a path of edges, ignoring which way the edges of
+/// a directed graph point. Each component is sorted, and the components are sorted by their
+/// first vertex.
+pub fn connected_components<G, V>(graph: &G) -> Vec<Vec<V>>
+where
+    G: Graph<Vertex = V>,
+    V: Eq + Ord + Hash + Clone,
+{
+    let vertices = graph.vertices();
+    let index: HashMap<&V, usize> = vertices.iter().enumerate().map(|(i, v)| (v, i)).collect();
+
+    let mut sets = UnionFind::new(vertices.len());
+    for (i, v) in vertices.iter().enumerate() {
+        for n in graph.neighbors(v) {
+            if let Some(&j) = index.get(&n) {
+                sets.union(i, j);
+            }
+        }
+    }
+
+    let mut components: HashMap<usize, Vec<V>> = HashMap::new();
+    for (i, v) in vertices.iter().enumerate() {
+        components.entry(sets.find(i)).or_default().push(v.clone());
+    }
+
+    let components = components.into_values().map(sorted);
+    sorted(components)
+}
+
+/// The largest flow from a source to a sink, along with a minimum cut that limits it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxFlow<V> {
+    pub value: u64,
+    /// The flow along each edge that carries any.
+    pub flows: Vec<(V, V, u64)>,
+    /// The vertices still reachable from the source through edges with capacity to spare.
+    /// The edges leaving them are the minimum cut.
+    pub source_side: Vec<V>,
+}
+
+/// The edges of a flow network as indices, each followed by its reverse so that `e ^ 1` is the
+/// edge flow can be pushed back along.
+struct Residual {
+    to: Vec<usize>,
+    capacity: Vec<u64>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl Residual {
+    fn add_edge(&mut self, from: usize, to: usize, capacity: u64) {
+        self.edges[from].push(self.to.len());
+        self.to.push(to);
+        self.capacity.push(capacity);
+        self.edges[to].push(self.to.len());
+        self.to.push(from);
+        self.capacity.push(0);
+    }
+
+    /// The number of edges from `source` to each vertex along edges with capacity left, if
+    /// the vertex can be reached.
+    fn levels(&self, source: usize) -> Vec<Option<usize>> {
+        let mut level = vec![None; self.edges.len()];
+        level[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            for &e in &self.edges[v] {
+                let to = self.to[e];
+                if self.capacity[e] > 0 && level[to].is_none() {
+                    level[to] = level[v].map(|l| l + 1);
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        level
+    }
+
+    /// Pushes up to `limit` along a path from `v` to `sink` that goes one level further each
+    /// edge. `next` skips the edges of each vertex that can't take any more.
+    fn augment(
+        &mut self,
+        v: usize,
+        sink: usize,
+        limit: u64,
+        level: &[Option<usize>],
+        next: &mut [usize],
+    ) -> u64 {
+        if v == sink {
+            return limit;
+        }
+
+        while let Some(&e) = self.edges[v].get(next[v]) {
+            let to = self.to[e];
+            if self.capacity[e] > 0 && level[to] == level[v].map(|l| l + 1) {
+                let pushed = self.augment(to, sink, limit.min(self.capacity[e]), level, next);
+                if pushed > 0 {
+                    self.capacity[e] -= pushed;
+                    self.capacity[e ^ 1] += pushed;
+                    return pushed;
+                }
+            }
+            next[v] += 1;
+        }
+
+        0
+    }
+}
+
+/// Finds the largest flow from `source` to `sink` with Dinic's algorithm, where each edge's
+/// weight is its capacity. Returns `None` if either isn't a vertex of the graph.
+///
+/// # Panics
+/// Panics if `source` and `sink` are the same vertex.
+pub fn max_flow<G, V>(graph: &G, source: &V, sink: &V) -> Option<MaxFlow<V>>
+where
+    G: WeightedGraph<Vertex = V, Weight = u64>,
+    V: Eq + Hash + Clone,
+{
+    assert!(source != sink, "source and sink must be different vertices");
+
+    let vertices = graph.vertices();
+    let index: HashMap<&V, usize> = vertices.iter().enumerate().map(|(i, v)| (v, i)).collect();
+    let (&s, &t) = (index.get(source)?, index.get(sink)?);
+
+    let mut residual = Residual {
+        to: vec![],
+        capacity: vec![],
+        edges: vec![vec![]; vertices.len()],
+    };
+    let edges = graph.weighted_edges();
+    for (v1, v2, capacity) in &edges {
+        residual.add_edge(index[v1], index[v2], *capacity);
+    }
+
+    let mut value = 0;
+    loop {
+        let level = residual.levels(s);
+        if level[t].is_none() {
+            break;
+        }
+
+        let mut next = vec![0; vertices.len()];
+        loop {
+            let pushed = residual.augment(s, t, u64::MAX, &level, &mut next);
+            if pushed == 0 {
+                break;
+            }
+            value += pushed;
+        }
+    }
+
+    // edge i was added as residual edge 2i, and the flow along it is what it gave up
+    let flows = edges
+        .into_iter()
+        .enumerate()
+        .map(|(i, (v1, v2, capacity))| (v1, v2, capacity - residual.capacity[2 * i]))
+        .filter(|&(_, _, flow)| flow > 0)
+        .collect();
+
+    let level = residual.levels(s);
+    let source_side = vertices
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, _)| level[i].is_some())
+        .map(|(_, v)| v)
+        .collect();
+
+    Some(MaxFlow {
+        value,
+        flows,
+        source_side,
+    })
+}
+
+/// A split of a graph's vertices into two sides, with the number of edges between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut<V> {
+    pub weight: u64,
+    /// The vertices on one side of the cut. The rest of the vertices are on the other.
+    pub side: Vec<V>,
+}
+
+/// Finds the fewest edges of an undirected graph that split it in two, using the Stoer–Wagner
+/// algorithm. Returns `None` if the graph has fewer than two vertices.
+pub fn min_cut<G, V>(graph: &G) -> Option<MinCut<V>>
+where
+    G: Graph<Vertex = V>,
+    V: Eq + Ord + Hash + Clone,
+{
+    let vertices = graph.vertices();
+    if vertices.len() < 2 {
+        return None;
+    }
+
+    let index: HashMap<&V, usize> = vertices.iter().enumerate().map(|(i, v)| (v, i)).collect();
+    let mut weights: Vec<HashMap<usize, u64>> = vec![HashMap::new(); vertices.len()];
+    for (i, v) in vertices.iter().enumerate() {
+        for n in graph.neighbors(v) {
+            if let Some(&j) = index.get(&n)
+                && i != j
+            {
+                weights[i].insert(j, 1);
+                weights[j].insert(i, 1);
+            }
+        }
+    }
+
+    // vertices are merged as the search goes, and each remembers the original ones it holds
+    let mut members: Vec<Vec<usize>> = (0..vertices.len()).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..vertices.len()).collect();
+    let mut best: Option<(u64, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        // add the vertex most tightly connected to those added so far, until all are added
+        let mut connectivity = vec![0u64; vertices.len()];
+        let mut added = vec![false; vertices.len()];
+        let mut order = vec![];
+        let mut heap = BinaryHeap::from([(0, active[0])]);
+        while let Some((connection, v)) = heap.pop() {
+            if added[v] || connection != connectivity[v] {
+                continue;
+            }
+
+            added[v] = true;
+            order.push(v);
+            for (&u, &w) in &weights[v] {
+                if !added[u] {
+                    connectivity[u] += w;
+                    heap.push((connectivity[u], u));
+                }
+            }
+        }
+
+        if order.len() < active.len() {
+            // the vertices added are cut off from the rest already
+            let side = order.iter().flat_map(|&v| members[v].clone()).collect();
+            best = Some((0, side));
+            break;
+        }
+
+        // the last vertex added is cut from the rest by its connectivity, and merging it into
+        // the one before it keeps every smaller cut that separates other vertices
+        let (s, t) = (order[order.len() - 2], order[order.len() - 1]);
+        if best.as_ref().is_none_or(|(w, _)| connectivity[t] < *w) {
+            best = Some((connectivity[t], members[t].clone()));
+        }
+
+        let merged = std::mem::take(&mut members[t]);
+        members[s].extend(merged);
+        for (u, w) in std::mem::take(&mut weights[t]) {
+            weights[u].remove(&t);
+            if u != s {
+                *weights[s].entry(u).or_default() += w;
+                *weights[u].entry(s).or_default() += w;
+            }
+        }
+        active.retain(|&v| v != t);
+    }
+
+    let (weight, side) = best?;
+    let side = sorted(side.into_iter().map(|i| vertices[i].clone()));
+    Some(MinCut { weight, side })
+}
+
+/// The left and right vertices of a bipartite graph as indices, along with which right vertex
+/// each left vertex is matched with and the other way around.
+struct Matching {
+    adjacent: Vec<Vec<usize>>,
+    left: Vec<Option<usize>>,
+    right: Vec<Option<usize>>,
+    /// How many augmenting steps each left vertex is from an unmatched one, in the current phase.
+    distance: Vec<Option<usize>>,
+}
+
+impl Matching {
+    /// Layers the left vertices by how far they are from an unmatched left vertex along paths
+    /// that alternate between unmatched and matched edges, returning whether any of those
+    /// paths reaches an unmatched right vertex.
+    fn layer(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+        for (l, matched) in self.left.iter().enumerate() {
+            self.distance[l] = if matched.is_none() {
+                queue.push_back(l);
+                Some(0)
+            } else {
+                None
+            };
+        }
+
+        let mut found = false;
+        while let Some(l) = queue.pop_front() {
+            for &r in &self.adjacent[l] {
+                match self.right[r] {
+                    None => found = true,
+                    Some(l2) if self.distance[l2].is_none() => {
+                        self.distance[l2] = self.distance[l].map(|d| d + 1);
+                        queue.push_back(l2);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Flips the edges along an alternating path from `l` down the layers to an unmatched right
+    /// vertex, returning whether there was one.
+    fn augment(&mut self, l: usize) -> bool {
+        for i in 0..self.adjacent[l].len() {
+            let r = self.adjacent[l][i];
+            let free = match self.right[r] {
+                None => true,
+                Some(l2) => {
+                    self.distance[l2] == self.distance[l].map(|d| d + 1) && self.augment(l2)
+                }
+            };
+
+            if free {
+                self.left[l] = Some(r);
+                self.right[r] = Some(l);
+                return true;
+            }
+        }
+
+        // nothing further down from here, so don't come back this phase
+        self.distance[l] = None;
+        false
+    }
+}
+
+/// Pairs up as many of the `left` vertices as possible with one of their neighbors each, using
+/// the Hopcroft–Karp algorithm. No neighbor is paired twice, and the pairs are in the order of
+/// `left`.
+pub fn max_matching<G, V>(graph: &G, left: &[V]) -> Vec<(V, V)>
+where
+    G: Graph<Vertex = V>,
+    V: Eq + Hash + Clone,
+{
+    let mut right: Vec<V> = vec![];
+    let mut index: HashMap<V, usize> = HashMap::new();
+    let adjacent = left
+        .iter()
+        .map(|l| {
+            graph
+                .neighbors(l)
+                .into_iter()
+                .map(|r| {
+                    *index.entry(r.clone()).or_insert_with(|| {
+                        right.push(r);
+                        right.len() - 1
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut matching = Matching {
+        adjacent,
+        left: vec![None; left.len()],
+        right: vec![None; right.len()],
+        distance: vec![None; left.len()],
+    };
+    while matching.layer() {
+        for l in 0..left.len() {
+            if matching.left[l].is_none() {
+                matching.augment(l);
+            }
+        }
+    }
+
+    matching
+        .left
+        .iter()
+        .enumerate()
+        .filter_map(|(l, r)| Some((left[l].clone(), right[(*r)?].clone())))
+        .collect()
+}
+
+/// A pairing of each left vertex with a different neighbor, and the total weight of the edges
+/// between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment<V> {
+    pub cost: i64,
+    pub pairs: Vec<(V, V)>,
+}
+
+/// Pairs every one of the `left` vertices with a different neighbor so that the total weight of
+/// the edges between them is as small as possible, using the Hungarian algorithm. Negating
+/// the weights finds the largest total instead. Returns `None` if there's no way to pair them
+/// all.
+///
+/// This takes time cubic in the number of vertices, so it's meant for small cases.
+pub fn min_cost_assignment<G, V>(graph: &G, left: &[V]) -> Option<Assignment<V>>
+where
+    G: WeightedGraph<Vertex = V, Weight = i64>,
+    V: Eq + Ord + Hash + Clone,
+{
+    let right = sorted(
+        left.iter()
+            .flat_map(|l| graph.neighbors(l))
+            .collect::<HashSet<V>>(),
+    );
+    if right.len() < left.len() {
+        return None;
+    }
+
+    // a missing edge costs more than every edge together, so it's only used if it has to be
+    let missing = 1 + left
+        .iter()
+        .flat_map(|l| right.iter().filter_map(|r| graph.weight(l, r)))
+        .map(i64::abs)
+        .sum::<i64>();
+    let cost = |l: usize, r: usize| graph.weight(&left[l - 1], &right[r - 1]).unwrap_or(missing);
+
+    // rows and columns count from 1, with column 0 holding the row being added
+    let (rows, columns) = (left.len(), right.len());
+    let mut row_potential = vec![0; rows + 1];
+    let mut column_potential = vec![0; columns + 1];
+    let mut row_of = vec![0; columns + 1];
+    let mut previous = vec![0; columns + 1];
+    for row in 1..=rows {
+        row_of[0] = row;
+        let mut column = 0;
+        let mut slack = vec![i64::MAX; columns + 1];
+        let mut used = vec![false; columns + 1];
+
+        // grow a tree of tight edges from the new row until it reaches a free column
+        loop {
+            used[column] = true;
+            let r = row_of[column];
+            let mut delta = i64::MAX;
+            let mut next = 0;
+            for c in 1..=columns {
+                if used[c] {
+                    continue;
+                }
+
+                let reduced = cost(r, c) - row_potential[r] - column_potential[c];
+                if reduced < slack[c] {
+                    slack[c] = reduced;
+                    previous[c] = column;
+                }
+                if slack[c] < delta {
+                    delta = slack[c];
+                    next = c;
+                }
+            }
+
+            for c in 0..=columns {
+                if used[c] {
+                    row_potential[row_of[c]] += delta;
+                    column_potential[c] -= delta;
+                } else {
+                    slack[c] -= delta;
+                }
+            }
+
+            column = next;
+            if row_of[column] == 0 {
+                break;
+            }
+        }
+
+        // shift the rows along the tree so the new row gets a column
+        while column != 0 {
+            let before = previous[column];
+            row_of[column] = row_of[before];
+            column = before;
+        }
+    }
+
+    let mut pairs = vec![];
+    let mut total = 0;
+    for c in 1..=columns {
+        if row_of[c] != 0 {
+            let (l, r) = (&left[row_of[c] - 1], &right[c - 1]);
+            total += graph.weight(l, r)?;
+            pairs.push((l.clone(), r.clone()));
+        }
+    }
+
+    pairs.sort_by_key(|(l, _)| left.iter().position(|v| v == l));
+    Some(Assignment { cost: total, pairs })
+}
+
+/// Collects the maximal cliques of more than two vertices. [`maximal_cliques`] takes the
+/// smallest size to keep instead.
+///
+/// # Panics
+pub fn bron_kerbosch<G, V>(
+    graph: &G,
+    current: &HashSet<V>,
+    potential: &mut HashSet<V>,
+    excluded: &mut HashSet<V>,
+    cliques: &mut Vec<Vec<V>>,
+) where
+    G: Graph<Vertex = V>,
+    V: Eq + Ord + Hash + Clone,
+{
+    if potential.is_empty() && excluded.is_empty() {
+        if current.len() > 2 {
+            let mut clique: Vec<V> = current.iter().cloned().collect();
+            clique.sort_unstable();
+            cliques.push(clique);
+        }
+        return;
+    }
+
+    let pivot = potential
+        .union(excluded)
+        .max_by_key(|v| graph.neighbors_set(v).len())
+        .cloned()
+        .expect("potential and excluded are both empty when at least one should be non-empty");
+
+    let pivot_neighbors = graph.neighbors_set(&pivot);
+    let candidates: Vec<V> = potential.difference(&pivot_neighbors).cloned().collect();
+    for candidate in candidates {
+        let mut current2 = current.clone();
+        current2.insert(candidate.clone());
+
+        let candidate_neighbors = graph.neighbors_set(&candidate);
+        let mut potential2 = potential
+            .intersection(&candidate_neighbors)
+            .cloned()
+            .collect();
+
+        let mut excluded2 = excluded
+            .intersection(&candidate_neighbors)
+            .cloned()
+            .collect();
+
+        bron_kerbosch(graph, &current2, &mut potential2, &mut excluded2, cliques);
+
+        potential.remove(&candidate);
+        excluded.insert(candidate);
+    }
+}
+
+/// # Panics
+pub fn max_cliques<G, V>(graph: &G) -> Vec<Vec<V>>
+where
+    G: Graph<Vertex = V>,
+    V: std::cmp::Ord + std::cmp::Eq + std::hash::Hash + Clone,
+{
+    let mut cliques = vec![];
+    let current = HashSet::new();
+    let mut potentials = graph.vertices().into_iter().collect();
+    let mut excluded = HashSet::new();
+
+    bron_kerbosch(
+        graph,
+        &current,
+        &mut potentials,
+        &mut excluded,
+        &mut cliques,
+    );
+
+    if cliques.is_empty() {
+        return vec![];
+    }
+
+    let largest_size = cliques
+        .iter()
+        .map(Vec::len)
+        .max()
+        .expect("cliques should be non-empty");
+
+    let cliques = cliques.into_iter().filter(|c| c.len() == largest_size);
+    sorted(cliques)
+}
+
+/// Bron–Kerbosch over vertex indices. Each maximal clique of at least `min_size` vertices is
+/// passed to `report`, which returns the smallest size still wanted so that branches that
+/// cannot reach it are skipped.
+fn bron_kerbosch_bits(
+    rows: &[BitSet],
+    current: &mut Vec<usize>,
+    mut potential: BitSet,
+    mut excluded: BitSet,
+    min_size: &mut usize,
+    report: &mut dyn FnMut(&[usize]) -> usize,
+) {
+    if current.len() + potential.len() < *min_size {
+        return;
+    }
+
+    if potential.is_empty() && excluded.is_empty() {
+        *min_size = report(current);
+        return;
+    }
+
+    // the pivot with the most neighbors left to try leaves the fewest candidates
+    let pivot = potential
+        .union(&excluded)
+        .iter()
+        .max_by_key(|&u| potential.intersection_len(&rows[u]))
+        .expect("potential and excluded are both empty when at least one should be non-empty");
+
+    for candidate in potential
+        .difference(&rows[pivot])
+        .iter()
+        .collect::<Vec<_>>()
+    {
+        current.push(candidate);
+        bron_kerbosch_bits(
+            rows,
+            current,
+            potential.intersection(&rows[candidate]),
+            excluded.intersection(&rows[candidate]),
+            min_size,
+            report,
+        );
+        current.pop();
+
+        potential.remove(candidate);
+        excluded.insert(candidate);
+    }
+}
+
+fn search_cliques<V>(
+    graph: &BitAdjacencyMatrix<V>,
+    min_size: usize,
+    report: &mut dyn FnMut(&[usize]) -> usize,
+) {
+    bron_kerbosch_bits(
+        &graph.rows,
+        &mut vec![],
+        BitSet::full(graph.rows.len()),
+        BitSet::new(graph.rows.len()),
+        &mut min_size.max(1),
+        report,
+    );
+}
+
+fn to_vertices<V: Ord + Clone>(
+    graph: &BitAdjacencyMatrix<V>,
+    cliques: Vec<Vec<usize>>,
+) -> Vec<Vec<V>> {
+    let cliques = cliques
+        .into_iter()
+        .map(|c| sorted(c.into_iter().map(|i| graph.vertices[i].clone())));
+    sorted(cliques)
+}
+
+/// The same cliques as [`max_cliques`], found with bit sets in place of hash sets.
+pub fn max_cliques_bitset<V>(graph: &BitAdjacencyMatrix<V>) -> Vec<Vec<V>>
+where
+    V: Eq + Ord + Hash + Clone,
+{
+    let mut cliques: Vec<Vec<usize>> = vec![];
+    search_cliques(graph, 3, &mut |clique| {
+        if cliques.first().is_some_and(|c| c.len() < clique.len()) {
+            cliques.clear();
+        }
+        cliques.push(clique.to_vec());
+        clique.len()
+    });
+
+    to_vertices(graph, cliques)
+}
+
+/// Every maximal clique, one that no other vertex could join, with at least `min_size`
+/// vertices. Each clique is sorted, and the cliques are sorted.
+pub fn maximal_cliques<V>(graph: &BitAdjacencyMatrix<V>, min_size: usize) -> Vec<Vec<V>>
+where
+    V: Eq + Ord + Hash + Clone,
+{
+    let mut cliques = vec![];
+    search_cliques(graph, min_size, &mut |clique| {
+        cliques.push(clique.to_vec());
+        min_size
+    });
+
+    to_vertices(graph, cliques)
+}
+
+/// The number of maximal cliques with at least `min_size` vertices.
+pub fn count_maximal_cliques<V>(graph: &BitAdjacencyMatrix<V>, min_size: usize) -> usize {
+    let mut count = 0;
+    search_cliques(graph, min_size, &mut |_| {
+        count += 1;
+        min_size
+    });
+
+    count
+}
+
+/// Grows `current` by every vertex of `candidates` in turn, only ever adding vertices that come
+/// after the ones already tried so that each clique is built once.
+fn extend_cliques(
+    rows: &[BitSet],
+    current: &mut Vec<usize>,
+    mut candidates: BitSet,
+    k: usize,
+    visit: &mut dyn FnMut(&[usize]),
+) {
+    if current.len() == k {
+        visit(current);
+        return;
+    }
+
+    for v in candidates.iter().collect::<Vec<_>>() {
+        candidates.remove(v);
+        current.push(v);
+        extend_cliques(rows, current, candidates.intersection(&rows[v]), k, visit);
+        current.pop();
+    }
+}
+
+/// Calls `visit` with the indices of every clique of exactly `k` vertices, whether or not it is
+/// maximal. Indices can be turned back into vertices with [`BitAdjacencyMatrix::vertex`].
+pub fn for_each_k_clique<V>(
+    graph: &BitAdjacencyMatrix<V>,
+    k: usize,
+    mut visit: impl FnMut(&[usize]),
+) {
+    let candidates = BitSet::full(graph.rows.len());
+    extend_cliques(&graph.rows, &mut vec![], candidates, k, &mut visit);
+}
+
+/// Every clique of exactly `k` vertices. Each clique is sorted, and the cliques are sorted.
+pub fn k_cliques<V>(graph: &BitAdjacencyMatrix<V>, k: usize) -> Vec<Vec<V>>
+where
+    V: Ord + Clone,
+{
+    let mut cliques = vec![];
+    for_each_k_clique(graph, k, |clique| cliques.push(clique.to_vec()));
+    to_vertices(graph, cliques)
+}
+
+/// Every set of three vertices that are all connected to each other.
+pub fn triangles<V>(graph: &BitAdjacencyMatrix<V>) -> Vec<Vec<V>>
+where
+    V: Ord + Clone,
+{
+    k_cliques(graph, 3)
+}
+
+/// The number of cliques of exactly `k` vertices. The last vertex of each clique is counted
+/// from the size of the candidate set rather than added one at a time.
+pub fn count_k_cliques<V>(graph: &BitAdjacencyMatrix<V>, k: usize) -> usize {
+    fn count(rows: &[BitSet], mut candidates: BitSet, k: usize) -> usize {
+        match k {
+            0 => 1,
+            1 => candidates.len(),
+            _ => {
+                let mut total = 0;
+                for v in candidates.iter().collect::<Vec<_>>() {
+                    candidates.remove(v);
+                    total += count(rows, candidates.intersection(&rows[v]), k - 1);
+                }
+                total
+            }
+        }
+    }
+
+    count(&graph.rows, BitSet::full(graph.rows.len()), k)
+}
+
+type VertexLabel<'a, V> = Box<dyn Fn(&V) -> String + 'a>;
+type EdgeLabel<'a, V> = Box<dyn Fn(&V, &V) -> String + 'a>;
+
+/// Writes a graph in the DOT language read by Graphviz, optionally labelling vertices and edges
+/// and highlighting a clique or path.
+///
+/// ```
+/// use aoc::y2024::graph::{AdjacencyList, Dot};
+///
+/// let mut graph = AdjacencyList::new();
+/// graph.add_edge("a", "b");
+/// let dot = Dot::new("example").highlight_path(&["a", "b"]).render(&graph);
+/// assert!(dot.starts_with("graph \"example\" {"));
+/// ```
+pub struct Dot<'a, V> {
+    name: String,
+    vertex_label: Option<VertexLabel<'a, V>>,
+    edge_label: Option<EdgeLabel<'a, V>>,
+    highlighted_vertices: HashSet<V>,
+    highlighted_edges: HashSet<(V, V)>,
+}
+
+impl<'a, V> Dot<'a, V>
+where
+    V: Eq + Hash + Clone + fmt::Display,
+{
+    #[must_use]
+    pub fn new(name: &str) -> Dot<'a, V> {
+        Dot {
+            name: name.to_string(),
+            vertex_label: None,
+            edge_label: None,
+            highlighted_vertices: HashSet::new(),
+            highlighted_edges: HashSet::new(),
+        }
+    }
+
+    /// Labels each vertex with `label` in place of its name.
+    #[must_use]
+    pub fn vertex_label(mut self, label: impl Fn(&V) -> String + 'a) -> Self {
+        self.vertex_label = Some(Box::new(label));
+        self
+    }
+
+    #[must_use]
+    pub fn edge_label(mut self, label: impl Fn(&V, &V) -> String + 'a) -> Self {
+        self.edge_label = Some(Box::new(label));
+        self
+    }
+
+    /// Highlights `vertices` without any of the edges between them.
+    #[must_use]
+    pub fn highlight_vertices(mut self, vertices: &[V]) -> Self {
+        self.highlighted_vertices.extend(vertices.iter().cloned());
+        self
+    }
+
+    /// Highlights the vertices of `clique` and every edge between two of them.
+    #[must_use]
+    pub fn highlight_clique(mut self, clique: &[V]) -> Self {
+        for (i, v1) in clique.iter().enumerate() {
+            for v2 in &clique[i + 1..] {
+                self.highlighted_edges.insert((v1.clone(), v2.clone()));
+                self.highlighted_edges.insert((v2.clone(), v1.clone()));
+            }
+        }
+        self.highlighted_vertices.extend(clique.iter().cloned());
+        self
+    }
+
+    /// Highlights the vertices of `path` and the edge from each to the next.
+    #[must_use]
+    pub fn highlight_path(mut self, path: &[V]) -> Self {
+        for w in path.windows(2) {
+            self.highlighted_edges.insert((w[0].clone(), w[1].clone()));
+        }
+        self.highlighted_vertices.extend(path.iter().cloned());
+        self
+    }
+
+    /// Writes an undirected graph, with each edge drawn once.
+    pub fn render<G: Graph<Vertex = V>>(&self, graph: &G) -> String {
+        self.write(graph, "graph", "--", false)
+    }
+
+    /// Writes a directed graph, with an arrow for each edge.
+    pub fn render_directed<G: DirectedGraph<Vertex = V>>(&self, graph: &G) -> String {
+        self.write(graph, "digraph", "->", true)
+    }
+
+    fn write<G: Graph<Vertex = V>>(
+        &self,
+        graph: &G,
+        kind: &str,
+        arrow: &str,
+        directed: bool,
+    ) -> String {
+        use std::fmt::Write;
+
+        let highlighted = |v1: &V, v2: &V| {
+            self.highlighted_edges.contains(&(v1.clone(), v2.clone()))
+                || !directed && self.highlighted_edges.contains(&(v2.clone(), v1.clone()))
+        };
+
+        let mut dot = format!("{kind} {} {{\n", quote(&self.name));
+        for v in graph.vertices() {
+            let mut attributes = vec![];
+            if let Some(label) = &self.vertex_label {
+                attributes.push(format!("label={}", quote(&label(&v))));
+            }
+            if self.highlighted_vertices.contains(&v) {
+                attributes.push("style=filled, fillcolor=gold".to_string());
+            }
+            writeln!(dot, "    {}{};", quote(&v), brackets(&attributes)).unwrap();
+        }
+
+        for (v1, v2) in graph.edges() {
+            let mut attributes = vec![];
+            if let Some(label) = &self.edge_label {
+                attributes.push(format!("label={}", quote(&label(&v1, &v2))));
+            }
+            if highlighted(&v1, &v2) {
+                attributes.push("color=red, penwidth=2".to_string());
+            }
+            writeln!(
+                dot,
+                "    {} {arrow} {}{};",
+                quote(&v1),
+                quote(&v2),
+                brackets(&attributes)
+            )
+            .unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// A DOT identifier for `v`, quoted so that any name is allowed.
+fn quote(v: &impl fmt::Display) -> String {
+    let escaped = v
+        .to_string()
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+fn brackets(attributes: &[String]) -> String {
+    if attributes.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", attributes.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edges() {
+        let mut undirected = AdjacencyList::new();
+        undirected.add_edge("b", "a");
+        undirected.add_edge("b", "c");
+        assert_eq!(undirected.edges(), vec![("a", "b"), ("b", "c")]);
+
+        let mut directed = DirectedAdjacencyList::new();
+        directed.add_edge("b", "a");
+        directed.add_edge("b", "c");
+        directed.add_edge("c", "b");
+        assert_eq!(directed.edges(), vec![("b", "a"), ("b", "c"), ("c", "b")]);
+        assert!(!directed.contains_edge(&"a", &"b"));
+        assert_eq!(directed.out_neighbors(&"b"), vec!["a", "c"]);
+        assert_eq!(directed.in_neighbors(&"b"), vec!["c"]);
+        assert_eq!(directed.in_neighbors(&"a"), vec!["b"]);
+
+        let mut weighted = WeightedAdjacencyList::new();
+        weighted.add_edge("a", "b", 3);
+        weighted.add_undirected_edge("b", "c", 5);
+        weighted.add_edge("a", "b", 4);
+        assert_eq!(
+            weighted.weighted_edges(),
+            vec![("a", "b", 4), ("b", "c", 5), ("c", "b", 5)]
+        );
+        assert_eq!(weighted.weight(&"b", &"a"), None);
+        assert_eq!(weighted.in_neighbors(&"b"), vec!["a", "c"]);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut graph = DirectedAdjacencyList::new();
+        for (from, to) in [(5, 3), (3, 1), (5, 1), (4, 1), (1, 2)] {
+            graph.add_edge(from, to);
+        }
+
+        for order in [topological_sort(&graph), topological_sort_dfs(&graph)] {
+            let order = order.unwrap();
+            assert_eq!(order.len(), 5);
+            assert!(is_consistent(&graph, &order), "{order:?}");
+        }
+        assert!(is_consistent(&graph, &[4, 5, 2]));
+        assert!(!is_consistent(&graph, &[4, 2, 5, 1]));
+
+        graph.add_edge(2, 3);
+        for cycle in [topological_sort(&graph), topological_sort_dfs(&graph)] {
+            let cycle = cycle.unwrap_err();
+            assert_eq!(cycle.0.first(), cycle.0.last());
+            for w in cycle.0.windows(2) {
+                assert!(graph.contains_edge(&w[0], &w[1]), "{cycle}");
+            }
+            assert_eq!(cycle.0.len(), 4, "{cycle}");
+        }
+
+        let cycle = Cycle(vec!["a", "b", "a"]);
+        assert_eq!(cycle.to_string(), "a → b → a");
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let mut graph = DirectedAdjacencyList::new();
+        for (from, to) in [
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 4),
+            (5, 6),
+            (6, 7),
+            (7, 7),
+        ] {
+            graph.add_edge(from, to);
+        }
+
+        // the components form a chain, so there is only one topological order
+        let topological = vec![vec![1, 2, 3], vec![4, 5], vec![6], vec![7]];
+        let sort_each = |cs: Vec<Vec<i32>>| cs.into_iter().map(sorted).collect::<Vec<_>>();
+        let kosaraju = sort_each(strongly_connected_components_kosaraju(&graph));
+        assert_eq!(kosaraju, topological);
+        let mut tarjan = sort_each(strongly_connected_components(&graph));
+        tarjan.reverse();
+        assert_eq!(tarjan, topological);
+
+        let cycle = find_cycle(&graph).unwrap();
+        assert_eq!(cycle.0.first(), cycle.0.last());
+        for w in cycle.0.windows(2) {
+            assert!(graph.contains_edge(&w[0], &w[1]), "{cycle}");
+        }
+        assert!(find_cycle(&graph.induced_subgraph(&[1, 2, 4, 6])).is_none());
+    }
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.sets(), 3);
+
+        let mut graph = DirectedAdjacencyList::new();
+        graph.add_edge('c', 'a');
+        graph.add_edge('b', 'a');
+        graph.add_edge('d', 'e');
+        graph.add_vertex('f');
+        let expected = vec![vec!['a', 'b', 'c'], vec!['d', 'e'], vec!['f']];
+        assert_eq!(connected_components(&graph), expected);
+    }
+
+    #[test]
+    fn test_max_flow() {
+        let mut graph = WeightedAdjacencyList::new();
+        for (from, to, capacity) in [
+            ("s", "a", 16),
+            ("s", "b", 13),
+            ("a", "c", 12),
+            ("b", "a", 4),
+            ("b", "d", 14),
+            ("c", "b", 9),
+            ("c", "t", 20),
+            ("d", "c", 7),
+            ("d", "t", 4),
+        ] {
+            graph.add_edge(from, to, capacity);
+        }
+        graph.add_vertex("e");
+
+        let flow = max_flow(&graph, &"s", &"t").unwrap();
+        assert_eq!(flow.value, 23);
+        assert_eq!(flow.source_side, vec!["a", "b", "d", "s"]);
+        let cut: u64 = graph
+            .weighted_edges()
+            .into_iter()
+            .filter(|(v1, v2, _)| flow.source_side.contains(v1) && !flow.source_side.contains(v2))
+            .map(|(_, _, capacity)| capacity)
+            .sum();
+        assert_eq!(cut, flow.value);
+
+        // flow in is flow out everywhere but the source and sink
+        for v in ["a", "b", "c", "d"] {
+            let into: u64 = flow.flows.iter().filter(|f| f.1 == v).map(|f| f.2).sum();
+            let out: u64 = flow.flows.iter().filter(|f| f.0 == v).map(|f| f.2).sum();
+            assert_eq!(into, out, "{v}");
+        }
+        for (from, to, f) in &flow.flows {
+            assert!(*f <= graph.weight(from, to).unwrap());
+        }
+
+        assert_eq!(max_flow(&graph, &"s", &"e").unwrap().value, 0);
+        assert_eq!(max_flow(&graph, &"s", &"x"), None);
+    }
+
+    #[test]
+    fn test_min_cut() {
+        // two squares with diagonals, joined by two edges
+        let mut graph = AdjacencyList::new();
+        for side in [[1, 2, 3, 4], [5, 6, 7, 8]] {
+            for (i, &v1) in side.iter().enumerate() {
+                for &v2 in &side[i + 1..] {
+                    graph.add_edge(v1, v2);
+                }
+            }
+        }
+        graph.add_edge(1, 5);
+        graph.add_edge(4, 8);
+
+        let cut = min_cut(&graph).unwrap();
+        assert_eq!(cut.weight, 2);
+        assert!(
+            cut.side == vec![1, 2, 3, 4] || cut.side == vec![5, 6, 7, 8],
+            "{cut:?}"
+        );
+
+        // the global min cut is the smallest cut between vertex 1 and any other vertex
+        let mut network = WeightedAdjacencyList::new();
+        for (v1, v2) in graph.edges() {
+            network.add_undirected_edge(v1, v2, 1);
+        }
+        let smallest = (2..=8)
+            .map(|t| max_flow(&network, &1, &t).unwrap().value)
+            .min();
+        assert_eq!(smallest, Some(cut.weight));
+
+        graph.add_vertex(9);
+        let cut = min_cut(&graph).unwrap();
+        assert_eq!(cut.weight, 0);
+        assert!(cut.side == vec![9] || cut.side.len() == 8, "{cut:?}");
+
+        let mut single = AdjacencyList::new();
+        single.add_vertex(1);
+        assert_eq!(min_cut(&single), None);
+    }
+
+    #[test]
+    fn test_max_matching() {
+        // fields and the ticket positions whose values all fit the field's rules
+        let mut graph = DirectedAdjacencyList::new();
+        for (field, position) in [
+            ("class", "1"),
+            ("class", "2"),
+            ("row", "0"),
+            ("row", "1"),
+            ("row", "2"),
+            ("seat", "2"),
+        ] {
+            graph.add_edge(field, position);
+        }
+
+        let matching = max_matching(&graph, &["class", "row", "seat"]);
+        assert_eq!(matching, vec![("class", "1"), ("row", "0"), ("seat", "2")]);
+
+        // only two of the three can be paired when two share their only neighbor
+        let mut graph = AdjacencyList::new();
+        for (l, r) in [(1, 10), (2, 10), (3, 10), (3, 11)] {
+            graph.add_edge(l, r);
+        }
+        let matching = max_matching(&graph, &[1, 2, 3]);
+        assert_eq!(matching.len(), 2);
+        assert!(matching.contains(&(3, 11)));
+    }
+
+    #[test]
+    fn test_min_cost_assignment() {
+        let costs = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+        let mut graph = WeightedAdjacencyList::new();
+        for (l, row) in costs.iter().enumerate() {
+            for (r, &cost) in row.iter().enumerate() {
+                graph.add_edge(l, 10 + r, cost);
+            }
+        }
+
+        let assignment = min_cost_assignment(&graph, &[0, 1, 2]).unwrap();
+        assert_eq!(assignment.cost, 5);
+        assert_eq!(assignment.pairs, vec![(0, 11), (1, 10), (2, 12)]);
+
+        // the largest total comes from negating the weights
+        let mut negated = WeightedAdjacencyList::new();
+        for (l, r, cost) in graph.weighted_edges() {
+            negated.add_edge(l, r, -cost);
+        }
+        let assignment = min_cost_assignment(&negated, &[0, 1, 2]).unwrap();
+        assert_eq!(assignment.cost, -11);
+
+        // two rows with a column to spare, or with only one column between them
+        let assignment = min_cost_assignment(&graph, &[1, 2]).unwrap();
+        assert_eq!(assignment.pairs, vec![(1, 11), (2, 12)]);
+
+        let mut graph = WeightedAdjacencyList::new();
+        graph.add_edge("a", "x", 1);
+        graph.add_edge("b", "x", 2);
+        assert_eq!(min_cost_assignment(&graph, &["a", "b"]), None);
+        graph.add_edge("a", "y", 7);
+        let assignment = min_cost_assignment(&graph, &["a", "b"]).unwrap();
+        assert_eq!(assignment.cost, 9);
+    }
+
+    #[test]
+    fn test_bitset() {
+        let mut set = BitSet::new(130);
+        for i in [0, 63, 64, 129] {
+            set.insert(i);
+        }
+        set.remove(63);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 64, 129]);
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(129) && !set.contains(63));
+        assert_eq!(BitSet::full(130).difference(&set).len(), 127);
+        assert_eq!(BitSet::full(130).intersection_len(&set), 3);
+        assert!(set.difference(&set).is_empty());
+
+        let mut graph = AdjacencyList::new();
+        for (a, b) in [
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (4, 6),
+            (5, 6),
+            (6, 7),
+        ] {
+            graph.add_edge(a, b);
+        }
+        let bits = BitAdjacencyMatrix::from_graph(&graph);
+        assert_eq!(bits.edges(), graph.edges());
+        assert_eq!(
+            max_cliques_bitset(&bits),
+            vec![vec![1, 2, 3], vec![4, 5, 6]]
+        );
+        assert_eq!(max_cliques_bitset(&bits), max_cliques(&graph));
+    }
+
+    #[test]
+    fn test_cliques() {
+        // two triangles sharing an edge, four vertices all connected to each other and a lone edge
+        let mut graph = AdjacencyList::new();
+        for (a, b) in [(1, 2), (1, 3), (2, 3), (2, 4), (3, 4)] {
+            graph.add_edge(a, b);
+        }
+        for (a, b) in [(5, 6), (5, 7), (5, 8), (6, 7), (6, 8), (7, 8), (9, 10)] {
+            graph.add_edge(a, b);
+        }
+        let graph = BitAdjacencyMatrix::from_graph(&graph);
+
+        let all = vec![vec![1, 2, 3], vec![2, 3, 4], vec![5, 6, 7, 8], vec![9, 10]];
+        assert_eq!(maximal_cliques(&graph, 0), all);
+        assert_eq!(maximal_cliques(&graph, 3), all[..3]);
+        assert_eq!(count_maximal_cliques(&graph, 4), 1);
+
+        let expected = vec![
+            vec![1, 2, 3],
+            vec![2, 3, 4],
+            vec![5, 6, 7],
+            vec![5, 6, 8],
+            vec![5, 7, 8],
+            vec![6, 7, 8],
+        ];
+        assert_eq!(triangles(&graph), expected);
+        for k in 0..=5 {
+            assert_eq!(
+                count_k_cliques(&graph, k),
+                k_cliques(&graph, k).len(),
+                "{k}"
+            );
+        }
+        assert_eq!(count_k_cliques(&graph, 2), graph.edges().len());
+        assert_eq!(count_k_cliques(&graph, 4), 1);
+    }
+
+    #[test]
+    fn test_dot() {
+        let mut graph = AdjacencyList::new();
+        graph.add_edge("a", "b");
+        graph.add_edge("b", "c");
+        graph.add_edge("c", "a");
+        graph.add_vertex("d \"e\"");
+
+        let dot = Dot::new("triangle")
+            .vertex_label(|v: &&str| v.to_uppercase())
+            .highlight_path(&["b", "a"])
+            .render(&graph);
+        let expected = "\
+graph \"triangle\" {
+    \"a\" [label=\"A\", style=filled, fillcolor=gold];
+    \"b\" [label=\"B\", style=filled, fillcolor=gold];
+    \"c\" [label=\"C\"];
+    \"d \\\"e\\\"\" [label=\"D \\\"E\\\"\"];
+    \"a\" -- \"b\" [color=red, penwidth=2];
+    \"a\" -- \"c\";
+    \"b\" -- \"c\";
+}
+";
+        assert_eq!(dot, expected);
+
+        let mut directed = DirectedAdjacencyList::new();
+        directed.add_edge(1, 2);
+        directed.add_edge(2, 3);
+        let dot = Dot::new("chain")
+            .edge_label(|v1, v2| format!("{v1}{v2}"))
+            .highlight_path(&[2, 1])
+            .render_directed(&directed);
+        assert!(dot.starts_with("digraph \"chain\" {\n"));
+        assert!(dot.contains("    \"1\" -> \"2\" [label=\"12\"];\n"));
+        assert!(dot.contains("    \"2\" [style=filled, fillcolor=gold];\n"));
+
+        let dot = Dot::new("ends")
+            .highlight_vertices(&[1, 3])
+            .render_directed(&directed);
+        assert!(dot.contains("    \"3\" [style=filled, fillcolor=gold];\n"));
+        assert!(!dot.contains("color=red"));
+    }
 }
@@ -16,6 +16,8 @@ You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use crate::profile;
+use crate::y2024::traversal::{Bfs, Dfs};
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
@@ -27,8 +29,29 @@ pub trait Maze {
     type Node: Hash + Eq + Ord + Copy + Clone + std::fmt::Debug;
     fn neighbors(&self, n: &Self::Node) -> Vec<(u32, Self::Node)>;
 
+    /// Visits the nodes reachable from `start` breadth first, ignoring the cost of each move.
+    fn bfs(
+        &self,
+        start: Self::Node,
+    ) -> Bfs<Self::Node, impl FnMut(&Self::Node) -> Vec<Self::Node>> {
+        Bfs::new(start, |n| {
+            self.neighbors(n).into_iter().map(|(_, n)| n).collect()
+        })
+    }
+
+    /// Visits the nodes reachable from `start` depth first, ignoring the cost of each move.
+    fn dfs(
+        &self,
+        start: Self::Node,
+    ) -> Dfs<Self::Node, impl FnMut(&Self::Node) -> Vec<Self::Node>> {
+        Dfs::new(start, |n| {
+            self.neighbors(n).into_iter().map(|(_, n)| n).collect()
+        })
+    }
+
     fn dijkstra(&self, source: &Self::Node) -> (Distances<Self::Node>, Predecessors<Self::Node>) {
         use std::cmp::Ordering::{Equal, Greater, Less};
+        let _span = profile::span("dijkstra");
 
         let mut dist = HashMap::new();
         dist.insert(*source, 0);
@@ -60,6 +83,9 @@ pub trait Maze {
                             {
                                 ps.push(current_node);
                             }
+
+                            // already queued at this distance when it was first reached
+                            return None;
                         }
                     }
 
@@ -80,6 +106,7 @@ pub trait Maze {
         start: &Self::Node,
         end: &Self::Node,
     ) -> Vec<Vec<Self::Node>> {
+        let _span = profile::span("shortest_paths");
         let mut shortest = vec![];
 
         let mut stack: Vec<(Vec<Self::Node>, &Self::Node)> = vec![];
@@ -0,0 +1,195 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Breadth and depth first traversals over anything with successors, such as a
+//! [`Graph`](crate::y2024::graph::Graph) or [`Maze`](crate::y2024::maze::Maze).
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A node reached by a traversal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Visit<N> {
+    pub node: N,
+    /// The number of edges between the start and the node.
+    pub depth: usize,
+    /// The node this one was reached from, or `None` for the start.
+    pub parent: Option<N>,
+}
+
+/// The path from the start to `node` by following parents back, if `node` has been reached.
+fn path_to<N: Eq + Hash + Clone>(parents: &HashMap<N, Option<N>>, node: &N) -> Option<Vec<N>> {
+    let mut path = vec![node.clone()];
+    let mut current = parents.get(node)?;
+    while let Some(parent) = current {
+        path.push(parent.clone());
+        current = &parents[parent];
+    }
+
+    path.reverse();
+    Some(path)
+}
+
+/// Visits nodes in order of how many edges away from the start they are.
+pub struct Bfs<N, F> {
+    queue: VecDeque<(N, usize)>,
+    parents: HashMap<N, Option<N>>,
+    successors: F,
+}
+
+impl<N, F, I> Bfs<N, F>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    pub fn new(start: N, successors: F) -> Bfs<N, F> {
+        let mut parents = HashMap::new();
+        parents.insert(start.clone(), None);
+
+        Bfs {
+            queue: VecDeque::from([(start, 0)]),
+            parents,
+            successors,
+        }
+    }
+
+    /// The shortest path from the start to `node`, if the traversal has reached it yet.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        path_to(&self.parents, node)
+    }
+}
+
+impl<N, F, I> Iterator for Bfs<N, F>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = Visit<N>;
+
+    fn next(&mut self) -> Option<Visit<N>> {
+        let (node, depth) = self.queue.pop_front()?;
+        for n in (self.successors)(&node) {
+            if !self.parents.contains_key(&n) {
+                self.parents.insert(n.clone(), Some(node.clone()));
+                self.queue.push_back((n, depth + 1));
+            }
+        }
+
+        let parent = self.parents[&node].clone();
+        Some(Visit {
+            node,
+            depth,
+            parent,
+        })
+    }
+}
+
+/// Visits nodes by following edges as far as they go before backing up.
+pub struct Dfs<N, F> {
+    stack: Vec<(N, usize, Option<N>)>,
+    parents: HashMap<N, Option<N>>,
+    successors: F,
+}
+
+impl<N, F, I> Dfs<N, F>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    pub fn new(start: N, successors: F) -> Dfs<N, F> {
+        Dfs {
+            stack: vec![(start, 0, None)],
+            parents: HashMap::new(),
+            successors,
+        }
+    }
+
+    /// The path the traversal took from the start to `node`, if it has reached it yet.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        path_to(&self.parents, node)
+    }
+}
+
+impl<N, F, I> Iterator for Dfs<N, F>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = Visit<N>;
+
+    fn next(&mut self) -> Option<Visit<N>> {
+        loop {
+            let (node, depth, parent) = self.stack.pop()?;
+            if self.parents.contains_key(&node) {
+                continue;
+            }
+            self.parents.insert(node.clone(), parent.clone());
+
+            // pushed in reverse so successors are visited in the order they're given
+            let successors: Vec<N> = (self.successors)(&node)
+                .into_iter()
+                .filter(|n| !self.parents.contains_key(n))
+                .collect();
+            for n in successors.into_iter().rev() {
+                self.stack.push((n, depth + 1, Some(node.clone())));
+            }
+
+            return Some(Visit {
+                node,
+                depth,
+                parent,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2024::graph::{AdjacencyList, Graph};
+
+    #[test]
+    fn test_traversal() {
+        // a - b - c - d
+        //  \         /
+        //   e ------
+        let mut graph = AdjacencyList::new();
+        for (v1, v2) in [('a', 'b'), ('b', 'c'), ('c', 'd'), ('a', 'e'), ('e', 'd')] {
+            graph.add_edge(v1, v2);
+        }
+
+        let mut bfs = graph.bfs('a');
+        let visits: Vec<(char, usize)> = bfs.by_ref().map(|v| (v.node, v.depth)).collect();
+        let expected = vec![('a', 0), ('b', 1), ('e', 1), ('c', 2), ('d', 2)];
+        assert_eq!(visits, expected);
+        assert_eq!(bfs.path_to(&'d'), Some(vec!['a', 'e', 'd']));
+
+        let mut dfs = graph.dfs('a');
+        let visits: Vec<Visit<char>> = dfs.by_ref().collect();
+        let nodes: Vec<char> = visits.iter().map(|v| v.node).collect();
+        assert_eq!(nodes, vec!['a', 'b', 'c', 'd', 'e']);
+        assert_eq!(visits[4].depth, 4);
+        assert_eq!(visits[4].parent, Some('d'));
+        assert_eq!(dfs.path_to(&'e'), Some(vec!['a', 'b', 'c', 'd', 'e']));
+        assert_eq!(dfs.path_to(&'z'), None);
+    }
+}
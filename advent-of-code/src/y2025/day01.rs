@@ -31,6 +31,16 @@ fn parse(input: &str) -> impl Iterator<Item = isize> {
     })
 }
 
+/// Checks that the input looks like a list of left and right dial rotations.
+pub fn looks_like_input(input: &str) -> bool {
+    !input.trim().is_empty()
+        && input.lines().take(10).all(|line| {
+            line.strip_prefix('L')
+                .or_else(|| line.strip_prefix('R'))
+                .is_some_and(|n| n.parse::<isize>().is_ok())
+        })
+}
+
 pub fn part1(input: &str) -> usize {
     parse(input)
         .scan(START_DIAL_POSITION, |dial, rotation| {
@@ -60,6 +60,17 @@ fn parse(input: &str) -> impl Iterator<Item = usize> {
         .flat_map(|(start, stop)| start..=stop)
 }
 
+/// Checks that the input looks like a single line of comma separated ID ranges.
+pub fn looks_like_input(input: &str) -> bool {
+    let input = input.trim();
+    !input.is_empty()
+        && !input.contains('\n')
+        && input.split(',').all(|rng| {
+            rng.split_once('-')
+                .is_some_and(|(a, b)| a.parse::<usize>().is_ok() && b.parse::<usize>().is_ok())
+        })
+}
+
 pub fn part1(input: &str) -> usize {
     parse(input).filter(|&id| !is_part1_valid(id)).sum()
 }
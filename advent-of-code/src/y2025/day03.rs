@@ -48,6 +48,14 @@ fn parse(input: &str) -> Vec<Vec<usize>> {
         .collect()
 }
 
+/// Checks that the input looks like banks of batteries with joltages from 1 to 9.
+pub fn looks_like_input(input: &str) -> bool {
+    !input.trim().is_empty()
+        && input
+            .lines()
+            .all(|line| line.chars().all(|c| matches!(c, '1'..='9')))
+}
+
 pub fn part1(input: &str) -> usize {
     parse(input)
         .iter()
@@ -0,0 +1,97 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![cfg(feature = "y2024")]
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+const NETWORK: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+fn run(dir: &Path, args: &[&str]) -> Output {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code"))
+        .args(args)
+        .current_dir(dir)
+        .env("XDG_CACHE_HOME", dir.join("cache"))
+        .output()
+        .expect("Failed to run advent-of-code");
+    assert!(output.status.success(), "{output:?}");
+    output
+}
+
+#[test]
+fn test_cached_dot() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("network.txt"), NETWORK).unwrap();
+
+    let args = [
+        "2024-12-23",
+        "network.txt",
+        "--cached",
+        "--dot",
+        "network.dot",
+    ];
+    for _ in 0..2 {
+        let output = run(&dir, &args);
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("Part 1 Solution: 7\n"), "{stdout}");
+        assert!(!stdout.contains("(cached)"), "{stdout}");
+
+        let dot = std::fs::read_to_string(dir.join("network.dot")).unwrap();
+        assert!(
+            dot.contains("\"co\" [style=filled, fillcolor=gold];"),
+            "{dot}"
+        );
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
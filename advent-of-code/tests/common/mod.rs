@@ -0,0 +1,46 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Builds libaoc as a C dynamic library with `features`, returning the path to it.
+///
+/// The library crate is only an rlib by default, so this runs `cargo rustc` into a target
+/// directory of its own instead of relying on a library left over from an earlier build.
+pub fn build_cdylib(features: &str) -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cdylib");
+
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .arg("rustc")
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml"))
+        .args(["--lib", "--crate-type", "cdylib", "--features", features])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .expect("Failed to run cargo");
+    assert!(status.success(), "Failed to build libaoc as a cdylib");
+
+    ["libaoc.so", "libaoc.dylib"]
+        .into_iter()
+        .map(|name| target_dir.join("debug").join(name))
+        .find(|path| path.exists())
+        .expect("Failed to find libaoc")
+}
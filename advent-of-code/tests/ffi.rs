@@ -0,0 +1,68 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![cfg(all(feature = "ffi", feature = "y2024", unix))]
+
+mod common;
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_header_up_to_date() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let generated = std::fs::read_to_string(Path::new(env!("OUT_DIR")).join("aoc.h")).unwrap();
+    let committed = std::fs::read_to_string(manifest_dir.join("include/aoc.h")).unwrap();
+    assert!(
+        generated == committed,
+        "include/aoc.h is out of date, copy {}/aoc.h over it",
+        env!("OUT_DIR")
+    );
+}
+
+#[test]
+fn test_c_program() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library = common::build_cdylib("ffi");
+    let lib_dir = library.parent().unwrap();
+    let program = std::env::temp_dir().join(format!("aoc-ffi-test-{}", std::process::id()));
+
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".into()))
+        .arg(manifest_dir.join("tests/ffi/solve.c"))
+        .arg("-I")
+        .arg(env!("OUT_DIR"))
+        .arg("-L")
+        .arg(lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-laoc")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("Failed to run the C compiler");
+    assert!(status.success(), "Failed to compile tests/ffi/solve.c");
+
+    // cargo points the library path at its own target directory, which would take precedence
+    // over the rpath and could load an older libaoc
+    let status = Command::new(&program)
+        .env("LD_LIBRARY_PATH", lib_dir)
+        .env("DYLD_LIBRARY_PATH", lib_dir)
+        .status()
+        .expect("Failed to run the C test program");
+    std::fs::remove_file(&program).unwrap();
+    assert!(status.success(), "C test program failed");
+}
@@ -0,0 +1,66 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                    \
+    do {                                                               \
+        if (!(cond)) {                                                 \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+            failures++;                                                \
+        }                                                              \
+    } while (0)
+
+int main(void) {
+    const char *input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    char buf[64];
+    size_t len;
+
+    len = sizeof(buf);
+    CHECK(aoc_solve(2024, 1, 1, input, strlen(input), buf, &len) == AOC_OK);
+    CHECK(strcmp(buf, "11") == 0);
+    CHECK(len == 2);
+
+    len = sizeof(buf);
+    CHECK(aoc_solve(2024, 1, 2, input, strlen(input), buf, &len) == AOC_OK);
+    CHECK(strcmp(buf, "31") == 0);
+
+    len = 2;
+    CHECK(aoc_solve(2024, 1, 2, input, strlen(input), buf, &len) == AOC_BUFFER_TOO_SMALL);
+    CHECK(len == 3);
+
+    len = sizeof(buf);
+    CHECK(aoc_solve(2015, 1, 1, input, strlen(input), buf, &len) == AOC_UNKNOWN_PUZZLE);
+    CHECK(aoc_solve(2024, 1, 3, input, strlen(input), buf, &len) == AOC_INVALID_ARGUMENT);
+    CHECK(aoc_solve(2024, 17, 1, input, strlen(input), buf, &len) == AOC_SOLUTION_PANICKED);
+
+    len = 0;
+    CHECK(aoc_list_puzzles(NULL, &len) == AOC_BUFFER_TOO_SMALL);
+    char puzzles[4096];
+    CHECK(len <= sizeof(puzzles));
+    CHECK(aoc_list_puzzles(puzzles, &len) == AOC_OK);
+    CHECK(strncmp(puzzles, "2024-12-01\n2024-12-02\n", 22) == 0);
+    CHECK(strlen(puzzles) == len);
+
+    return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,43 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![cfg(all(feature = "python", unix))]
+
+mod common;
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_python_module() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library = common::build_cdylib("python");
+
+    // Python only imports extension modules named after the module
+    let module_dir = std::env::temp_dir().join(format!("aoc-python-test-{}", std::process::id()));
+    std::fs::create_dir_all(&module_dir).unwrap();
+    std::fs::copy(library, module_dir.join("aoc.so")).unwrap();
+
+    let status = Command::new(std::env::var("PYTHON").unwrap_or_else(|_| "python3".into()))
+        .arg(manifest_dir.join("tests/python/test_aoc.py"))
+        .env("PYTHONPATH", &module_dir)
+        .status()
+        .expect("Failed to run python");
+    std::fs::remove_dir_all(&module_dir).unwrap();
+    assert!(status.success(), "Python tests failed");
+}
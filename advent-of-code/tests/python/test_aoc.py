@@ -0,0 +1,71 @@
+# Advent of Code solutions written in the Rust programming language
+# Copyright (C) 2025 Alexander Bechanko
+#
+# This program is free software: you can redistribute it and/or modify
+# it under the terms of the GNU General Public License as published by
+# the Free Software Foundation, either version 3 of the License, or
+# (at your option) any later version.
+#
+# This program is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+# GNU General Public License for more details.
+#
+# You should have received a copy of the GNU General Public License
+# along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+import unittest
+
+import aoc
+
+
+class TestSolve(unittest.TestCase):
+    INPUT = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"
+
+    def test_solve(self):
+        self.assertEqual("11", aoc.solve(2024, 1, 1, self.INPUT))
+        self.assertEqual("31", aoc.solve(2024, 1, 2, self.INPUT))
+
+    def test_errors(self):
+        with self.assertRaises(LookupError):
+            aoc.solve(2015, 1, 1, self.INPUT)
+        with self.assertRaises(ValueError):
+            aoc.solve(2024, 1, 3, self.INPUT)
+        with self.assertRaises(RuntimeError):
+            aoc.solve(2024, 17, 1, self.INPUT)
+
+    def test_puzzles(self):
+        self.assertIn("2024-12-23", aoc.puzzles())
+
+
+class TestGraph(unittest.TestCase):
+    def test_max_cliques(self):
+        adjacency = {
+            "kh": ["tc", "qp"],
+            "tc": ["qp", "wh"],
+            "qp": ["wh"],
+            "wh": ["kh"],
+            "de": ["cg"],
+        }
+        cliques = aoc.max_cliques(adjacency)
+        self.assertEqual([["kh", "qp", "tc", "wh"]], [sorted(c) for c in cliques])
+
+    def test_dijkstra(self):
+        adjacency = {
+            (0, 0): [(1, (0, 1)), (4, (1, 1))],
+            (0, 1): [(3, (1, 1))],
+        }
+        distances, predecessors = aoc.dijkstra(adjacency, (0, 0))
+        self.assertEqual({(0, 0): 0, (0, 1): 1, (1, 1): 4}, distances)
+        self.assertEqual([(0, 0)], predecessors[(0, 1)])
+        self.assertEqual({(0, 0), (0, 1)}, set(predecessors[(1, 1)]))
+
+    def test_shortest_paths(self):
+        adjacency = {"a": [(1, "b"), (2, "c")], "b": [(1, "c")]}
+        paths = aoc.shortest_paths(adjacency, "a", "c")
+        self.assertEqual([["a", "b", "c"], ["a", "c"]], sorted(paths))
+        self.assertEqual([], aoc.shortest_paths(adjacency, "c", "a"))
+
+
+if __name__ == "__main__":
+    unittest.main()
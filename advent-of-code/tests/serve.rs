@@ -0,0 +1,97 @@
+/*
+Advent of Code solutions written in the Rust programming language
+Copyright (C) 2025 Alexander Bechanko
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![cfg(feature = "y2024")]
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::OnceLock;
+
+fn server() -> SocketAddr {
+    static ADDR: OnceLock<SocketAddr> = OnceLock::new();
+    *ADDR.get_or_init(|| {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind to localhost");
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || aoc::serve::serve(&listener));
+        addr
+    })
+}
+
+fn request(method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(server()).expect("Failed to connect to server");
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").expect("Malformed response");
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .expect("Missing status code");
+
+    (status, body.to_string())
+}
+
+#[test]
+fn test_puzzles() {
+    let (status, body) = request("GET", "/puzzles", "");
+    assert_eq!(200, status);
+    assert!(body.starts_with('['));
+    assert!(body.contains(
+        r#"{"id":"2024-12-16","year":2024,"day":16,"title":"Reindeer Maze","tags":["grid","pathfinding"]}"#
+    ));
+}
+
+#[test]
+fn test_solve() {
+    let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    let (status, body) = request("POST", "/solve/2024/1/1", input);
+    assert_eq!(200, status, "{body}");
+    assert!(body.starts_with(r#"{"id":"2024-12-01","part":1,"answer":"11","elapsed_ms":"#));
+
+    let (status, body) = request("POST", "/solve/2024/01/2", input);
+    assert_eq!(200, status, "{body}");
+    assert!(body.contains(r#""answer":"31""#));
+}
+
+#[test]
+fn test_errors() {
+    let tests = vec![
+        ("POST", "/solve/2015/1/1", 404),
+        ("POST", "/solve/2024/1/3", 400),
+        ("POST", "/solve/2024/x/1", 400),
+        ("GET", "/solve/2024/1/1", 405),
+        ("POST", "/puzzles", 405),
+        ("GET", "/", 404),
+        ("POST", "/solve/2024/17/1", 422),
+    ];
+
+    for (method, path, expected) in tests {
+        let (status, body) = request(method, path, "not a puzzle input\n");
+        assert_eq!(expected, status, "{method} {path}");
+        assert!(body.starts_with(r#"{"error":"#), "{body}");
+    }
+}